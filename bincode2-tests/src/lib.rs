@@ -0,0 +1,3 @@
+//! Tests of `#[serializer("bincode2")]`.
+//!
+//! See the `tests` directory.
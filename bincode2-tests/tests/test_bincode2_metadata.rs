@@ -0,0 +1,64 @@
+use tskit::metadata::MetadataRoundtrip;
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, tskit::metadata::NodeMetadata)]
+#[serializer("bincode2")]
+struct NodeMetadata {
+    label: String,
+    values: Vec<f64>,
+}
+
+#[derive(
+    Debug, PartialEq, serde::Serialize, serde::Deserialize, tskit::metadata::MutationMetadata,
+)]
+#[serializer("bincode2")]
+struct MutationMetadata(u32);
+
+fn node_metadata() -> NodeMetadata {
+    NodeMetadata {
+        label: "ancestor".to_string(),
+        values: vec![1.0, -2.5],
+    }
+}
+
+#[test]
+fn test_roundtrip() {
+    let md = node_metadata();
+    let encoded = md.encode().unwrap();
+    assert_eq!(
+        encoded,
+        bincode::serde::encode_to_vec(&md, bincode::config::standard()).unwrap()
+    );
+    assert_eq!(NodeMetadata::decode(&encoded).unwrap(), md);
+}
+
+#[test]
+fn test_decode_error() {
+    assert!(matches!(
+        NodeMetadata::decode(&[0xff]),
+        Err(tskit::metadata::MetadataError::RoundtripError { .. })
+    ));
+}
+
+#[test]
+fn test_table_roundtrip() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    let node = tables
+        .add_node_with_metadata(0, 1.0, -1, -1, &node_metadata())
+        .unwrap();
+    let site = tables.add_site(5., Some(b"A")).unwrap();
+    let mutation = tables
+        .add_mutation_with_metadata(site, node, -1, 1.0, Some(b"T"), &MutationMetadata(3))
+        .unwrap();
+    let decoded = tables
+        .nodes()
+        .metadata::<NodeMetadata>(node)
+        .unwrap()
+        .unwrap();
+    assert_eq!(decoded, node_metadata());
+    let decoded = tables
+        .mutations()
+        .metadata::<MutationMetadata>(mutation)
+        .unwrap()
+        .unwrap();
+    assert_eq!(decoded, MutationMetadata(3));
+}
@@ -743,12 +743,12 @@ macro_rules! migration_table_add_row_details {
         let rv = unsafe {
             $crate::sys::bindings::tsk_migration_table_add_row(
                 $table,
-                $span.0.into().into(),
-                $span.1.into().into(),
-                $node.into().into(),
-                $source_dest.0.into().into(),
-                $source_dest.1.into().into(),
-                $time.into().into(),
+                Into::<$crate::Position>::into($span.0).into(),
+                Into::<$crate::Position>::into($span.1).into(),
+                Into::<$crate::NodeId>::into($node).into(),
+                Into::<$crate::PopulationId>::into($source_dest.0).into(),
+                Into::<$crate::PopulationId>::into($source_dest.1).into(),
+                Into::<$crate::Time>::into($time).into(),
                 $metadata,
                 $metadata_len,
             )
@@ -805,6 +805,38 @@ macro_rules! migration_table_add_row_with_metadata {
     };
 }
 
+macro_rules! migration_table_add_mass_migration {
+    ($(#[$attr:meta])* => $name: ident, $self: ident, $table: expr) => {
+        $(#[$attr])*
+        pub fn $name<T,SOURCE,DEST,LEFT,RIGHT,N>(&mut $self,
+                     time: T,
+                     source: SOURCE,
+                     dest: DEST,
+                     span: (LEFT, RIGHT),
+                     node: N)
+        -> Result<$crate::MigrationId, $crate::TskitError>
+        where
+            T: Into<$crate::Time>,
+            SOURCE: Into<$crate::PopulationId>,
+            DEST: Into<$crate::PopulationId>,
+            LEFT: Into<$crate::Position>,
+            RIGHT: Into<$crate::Position>,
+            N: Into<$crate::NodeId>,
+        {
+            let source = source.into();
+            let dest = dest.into();
+            if source == dest {
+                return Err($crate::TskitError::ValueError {
+                    got: format!("source and destination population {}", source),
+                    expected: "different source and destination populations".to_string(),
+                });
+            }
+            let source_dest = (source, dest);
+            migration_table_add_row_details!(span, node, source_dest, time, std::ptr::null(), 0, $table)
+        }
+    };
+}
+
 #[cfg(feature = "provenance")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "provenance")))]
 macro_rules! provenance_table_add_row {
@@ -874,6 +906,29 @@ macro_rules! row_lending_iterator_get {
     };
 }
 
+macro_rules! row_view_decode_metadata {
+    ($(#[$attr:meta])* $metadata: ident) => {
+        /// Decode the metadata of the current row without copying it.
+        ///
+        /// # Returns
+        ///
+        /// * `Some(Ok(T))` if the row has metadata and decoding succeeded.
+        /// * `Some(Err(_))` if the row has metadata and decoding failed.
+        /// * `None` if the row has no metadata.
+        ///
+        /// # Errors
+        ///
+        /// * [`TskitError::MetadataError`](crate::TskitError::MetadataError) if decoding fails.
+        $(#[$attr])*
+        pub fn decode_metadata<T: $crate::metadata::$metadata>(
+            &self,
+        ) -> Option<Result<T, $crate::TskitError>> {
+            let buffer = self.metadata?;
+            Some(decode_metadata_row!(T, buffer).map_err(|e| e.into()))
+        }
+    };
+}
+
 macro_rules! optional_container_comparison {
     ($lhs: expr, $rhs: expr) => {
         if let Some(value) = &$lhs {
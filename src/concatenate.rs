@@ -0,0 +1,188 @@
+use crate::sys::bindings as ll_bindings;
+use crate::NodeId;
+use crate::TableSortOptions;
+use crate::TreeSequence;
+use crate::TreeSequenceFlags;
+use crate::TskitError;
+use ll_bindings::tsk_id_t;
+use ll_bindings::tsk_size_t;
+
+fn as_raw_parts(data: &Option<Vec<u8>>) -> (*const libc::c_char, tsk_size_t) {
+    match data {
+        Some(data) => (
+            data.as_ptr().cast::<libc::c_char>(),
+            data.len() as tsk_size_t,
+        ),
+        None => (std::ptr::null(), 0),
+    }
+}
+
+fn check_row(rv: tsk_id_t) -> Result<tsk_id_t, TskitError> {
+    match rv {
+        rv if rv < 0 => Err(TskitError::ErrorCode { code: rv }),
+        rv => Ok(rv),
+    }
+}
+
+fn sequence_length(treeseq: &TreeSequence) -> f64 {
+    // SAFETY: the tree sequence pointer is not null
+    unsafe { ll_bindings::tsk_treeseq_get_sequence_length(treeseq.as_ptr()) }
+}
+
+pub(crate) fn concatenate(
+    first: &TreeSequence,
+    others: &[&TreeSequence],
+) -> Result<TreeSequence, TskitError> {
+    let samples = first.sample_nodes();
+    if let Some(other) = others.iter().find(|o| o.sample_nodes() != samples) {
+        return Err(TskitError::ValueError {
+            got: format!("a tree sequence with samples {:?}", other.sample_nodes()),
+            expected: format!("the same samples as the first tree sequence, {:?}", samples),
+        });
+    }
+    // Populations and individuals are not merged, so nodes can only
+    // keep their ids if all tree sequences share the same tables.
+    if let Some(other) = others.iter().find(|o| {
+        !first.populations().iter().eq(o.populations().iter())
+            || !first.individuals().iter().eq(o.individuals().iter())
+    }) {
+        return Err(TskitError::ValueError {
+            got: format!(
+                "a tree sequence with {} populations and {} individuals, not all equal to those of the first",
+                other.populations().num_rows(),
+                other.individuals().num_rows()
+            ),
+            expected: "the same population and individual tables as the first tree sequence"
+                .to_string(),
+        });
+    }
+
+    let mut tables = first.dump_tables()?;
+    let mut offset = sequence_length(first);
+    let tables_ptr = tables.as_mut_ptr();
+    // SAFETY: the tables pointer is not null
+    unsafe {
+        (*tables_ptr).sequence_length =
+            offset + others.iter().map(|o| sequence_length(o)).sum::<f64>()
+    };
+
+    for other in others {
+        // Samples keep their ids, and all other nodes are new rows.
+        let mut node_map = vec![NodeId::NULL; other.nodes().num_rows().as_usize()];
+        for &s in samples {
+            node_map[s.as_usize()] = s;
+        }
+        for node in other.nodes().iter() {
+            if node_map[node.id.as_usize()].is_null() {
+                let (metadata, metadata_length) = as_raw_parts(&node.metadata);
+                // SAFETY: the tables pointer is not null
+                let id = check_row(unsafe {
+                    ll_bindings::tsk_node_table_add_row(
+                        &mut (*tables_ptr).nodes,
+                        node.flags.bits(),
+                        node.time.into(),
+                        node.population.into(),
+                        node.individual.into(),
+                        metadata,
+                        metadata_length,
+                    )
+                })?;
+                node_map[node.id.as_usize()] = id.into();
+            }
+        }
+        let map = |u: NodeId| -> tsk_id_t {
+            match u.is_null() {
+                true => u.into(),
+                false => node_map[u.as_usize()].into(),
+            }
+        };
+
+        for edge in other.edges().iter() {
+            let (metadata, metadata_length) = as_raw_parts(&edge.metadata);
+            // SAFETY: the tables pointer is not null
+            check_row(unsafe {
+                ll_bindings::tsk_edge_table_add_row(
+                    &mut (*tables_ptr).edges,
+                    f64::from(edge.left) + offset,
+                    f64::from(edge.right) + offset,
+                    map(edge.parent),
+                    map(edge.child),
+                    metadata,
+                    metadata_length,
+                )
+            })?;
+        }
+
+        for migration in other.migrations().iter() {
+            let (metadata, metadata_length) = as_raw_parts(&migration.metadata);
+            // SAFETY: the tables pointer is not null
+            check_row(unsafe {
+                ll_bindings::tsk_migration_table_add_row(
+                    &mut (*tables_ptr).migrations,
+                    f64::from(migration.left) + offset,
+                    f64::from(migration.right) + offset,
+                    map(migration.node),
+                    migration.source.into(),
+                    migration.dest.into(),
+                    migration.time.into(),
+                    metadata,
+                    metadata_length,
+                )
+            })?;
+        }
+
+        // Sites and mutations are appended in order, so their
+        // new ids are their old ids plus the number of existing rows.
+        // SAFETY: the tables pointer is not null
+        let (site_offset, mutation_offset) = unsafe {
+            (
+                (*tables_ptr).sites.num_rows as tsk_id_t,
+                (*tables_ptr).mutations.num_rows as tsk_id_t,
+            )
+        };
+        for site in other.sites().iter() {
+            let (ancestral_state, ancestral_state_length) = as_raw_parts(&site.ancestral_state);
+            let (metadata, metadata_length) = as_raw_parts(&site.metadata);
+            // SAFETY: the tables pointer is not null
+            check_row(unsafe {
+                ll_bindings::tsk_site_table_add_row(
+                    &mut (*tables_ptr).sites,
+                    f64::from(site.position) + offset,
+                    ancestral_state,
+                    ancestral_state_length,
+                    metadata,
+                    metadata_length,
+                )
+            })?;
+        }
+        for mutation in other.mutations().iter() {
+            let (derived_state, derived_state_length) = as_raw_parts(&mutation.derived_state);
+            let (metadata, metadata_length) = as_raw_parts(&mutation.metadata);
+            let site: tsk_id_t = mutation.site.into();
+            let parent: tsk_id_t = match mutation.parent.is_null() {
+                true => mutation.parent.into(),
+                false => tsk_id_t::from(mutation.parent) + mutation_offset,
+            };
+            // SAFETY: the tables pointer is not null
+            check_row(unsafe {
+                ll_bindings::tsk_mutation_table_add_row(
+                    &mut (*tables_ptr).mutations,
+                    site + site_offset,
+                    map(mutation.node),
+                    parent,
+                    mutation.time.into(),
+                    derived_state,
+                    derived_state_length,
+                    metadata,
+                    metadata_length,
+                )
+            })?;
+        }
+
+        offset += sequence_length(other);
+    }
+
+    tables.full_sort(TableSortOptions::default())?;
+    tables.build_index()?;
+    tables.tree_sequence(TreeSequenceFlags::default())
+}
@@ -22,12 +22,25 @@ impl PartialEq for EdgeTableRow {
         self.id == other.id
             && self.parent == other.parent
             && self.child == other.child
-            && crate::util::partial_cmp_equal(&self.left, &other.left)
-            && crate::util::partial_cmp_equal(&self.right, &other.right)
+            && crate::util::hash_eq_f64(self.left, other.left)
+            && crate::util::hash_eq_f64(self.right, other.right)
             && self.metadata == other.metadata
     }
 }
 
+impl Eq for EdgeTableRow {}
+
+impl std::hash::Hash for EdgeTableRow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.parent.hash(state);
+        self.child.hash(state);
+        crate::util::hash_f64(self.left, state);
+        crate::util::hash_f64(self.right, state);
+        self.metadata.hash(state);
+    }
+}
+
 fn make_edge_table_row(table: &EdgeTable, pos: tsk_id_t) -> Option<EdgeTableRow> {
     Some(EdgeTableRow {
         id: pos.into(),
@@ -62,6 +75,15 @@ impl Iterator for EdgeTableIterator {
     }
 }
 
+impl<'a> IntoIterator for &'a EdgeTable {
+    type Item = EdgeTableRow;
+    type IntoIter = EdgeTableRefIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        crate::table_iterator::make_table_iterator::<&EdgeTable>(self)
+    }
+}
+
 /// Row of an [`EdgeTable`]
 #[derive(Debug)]
 pub struct EdgeTableRowView<'a> {
@@ -86,6 +108,40 @@ impl<'a> EdgeTableRowView<'a> {
             metadata: None,
         }
     }
+
+    row_view_decode_metadata!(
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # #[cfg(feature = "derive")] {
+        /// use streaming_iterator::StreamingIterator;
+        ///
+        /// #[derive(serde::Serialize, serde::Deserialize, tskit::metadata::EdgeMetadata)]
+        /// #[serializer("serde_json")]
+        /// struct Md {
+        ///     recombination_rate: f64,
+        /// }
+        ///
+        /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+        /// let parent = tables.add_node(0, 1.0, -1, -1).unwrap();
+        /// let child = tables.add_node(0, 0.0, -1, -1).unwrap();
+        /// for (left, right, recombination_rate) in [(0., 5., 1e-8), (5., 10., 1e-9)] {
+        ///     let md = Md { recombination_rate };
+        ///     tables
+        ///         .add_edge_with_metadata(left, right, parent, child, &md)
+        ///         .unwrap();
+        /// }
+        /// let mut total = 0.0;
+        /// let mut rows = tables.edges().lending_iter();
+        /// while let Some(row) = rows.next() {
+        ///     let md = row.decode_metadata::<Md>().unwrap().unwrap();
+        ///     total += md.recombination_rate * f64::from(row.right - row.left);
+        /// }
+        /// assert!((total - 5.5e-8).abs() < 1e-20);
+        /// # }
+        /// ```
+        EdgeMetadata);
 }
 
 impl<'a> PartialEq for EdgeTableRowView<'a> {
@@ -292,6 +348,51 @@ impl EdgeTable {
     build_table_column_slice_getter!(
         /// Get the left column as a slice of [`f64`] 
         => right, right_slice_raw, f64);
+    build_table_column_slice_mut_getter!(
+    /// Get the left column as a mutable slice
+    ///
+    /// # Note
+    ///
+    /// Changing coordinates may leave the edges unsorted and the
+    /// indexes invalid.
+    /// Sort the tables and rebuild the indexes before creating
+    /// a [`crate::TreeSequence`].
+    ///
+    /// See [`EdgeTable::right_slice_mut`] for an example.
+        => left, left_slice_mut, Position);
+    build_table_column_slice_mut_getter!(
+        /// Get the left column as a mutable slice of [`f64`]
+        ///
+        /// See [`EdgeTable::left_slice_mut`].
+        => left, left_slice_raw_mut, f64);
+    build_table_column_slice_mut_getter!(
+    /// Get the right column as a mutable slice
+    ///
+    /// # Note
+    ///
+    /// Changing coordinates may leave the edges unsorted and the
+    /// indexes invalid.
+    /// Sort the tables and rebuild the indexes before creating
+    /// a [`crate::TreeSequence`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// # tables.add_node(0, 1.0, -1, -1).unwrap();
+    /// # tables.add_node(tskit::NodeFlags::new_sample(), 0.0, -1, -1).unwrap();
+    /// tables.add_edge(0., 10., 0, 1).unwrap();
+    /// tables.edges_mut().right_slice_mut()[0] = 5.0.into();
+    /// assert_eq!(tables.edges().right(0), Some(5.0.into()));
+    /// tables.full_sort(tskit::TableSortOptions::default()).unwrap();
+    /// tables.build_index().unwrap();
+    /// ```
+        => right, right_slice_mut, Position);
+    build_table_column_slice_mut_getter!(
+        /// Get the right column as a mutable slice of [`f64`]
+        ///
+        /// See [`EdgeTable::right_slice_mut`].
+        => right, right_slice_raw_mut, f64);
     build_table_column_slice_getter!(
         /// Get the parent column as a slice
         => parent, parent_slice, NodeId);
@@ -304,6 +405,44 @@ impl EdgeTable {
     build_table_column_slice_getter!(
         /// Get the child column as a slice of the underlying integer type
         => child, child_slice_raw, ll_bindings::tsk_id_t);
+
+    /// Return an iterator over the `(parent, child, span)`
+    /// of each edge, where `span` is `right - left`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut edges = tskit::OwningEdgeTable::default();
+    /// edges.add_row(0., 10., 0, 1).unwrap();
+    /// edges.add_row(5., 10., 0, 2).unwrap();
+    /// edges.add_row(0., 5., 3, 2).unwrap();
+    /// let total = edges.spans().fold(0.0, |total, (_, _, span)| total + f64::from(span));
+    /// assert_eq!(total, 20.0);
+    /// let child_two: f64 = edges
+    ///     .spans()
+    ///     .filter(|&(_, child, _)| child == 2)
+    ///     .map(|(_, _, span)| f64::from(span))
+    ///     .sum();
+    /// assert_eq!(child_two, 10.0);
+    /// ```
+    pub fn spans(&self) -> impl Iterator<Item = (NodeId, NodeId, Position)> + '_ {
+        self.parent_slice()
+            .iter()
+            .zip(self.child_slice())
+            .zip(self.left_slice().iter().zip(self.right_slice()))
+            .map(|((&parent, &child), (&left, &right))| (parent, child, right - left))
+    }
+
+    /// Write the table as comma-separated values.
+    ///
+    /// See [`crate::NodeTable::write_csv`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::LibraryError`] if writing fails.
+    pub fn write_csv<W: std::io::Write>(&self, writer: &mut W) -> Result<(), TskitError> {
+        crate::table_csv::write_edges(self, writer)
+    }
 }
 
 build_owned_table_type!(
@@ -363,4 +502,58 @@ build_owned_table_type!(
 impl OwningEdgeTable {
     edge_table_add_row!(=> add_row, self, self.as_mut_ptr());
     edge_table_add_row_with_metadata!(=> add_row_with_metadata, self, self.as_mut_ptr());
+
+    /// Build a table from an iterator over
+    /// `(left, right, parent, child)` tuples.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by [`OwningEdgeTable::add_row`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rows = vec![(0., 10., 2, 0), (0., 10., 2, 1)];
+    /// let edges = tskit::OwningEdgeTable::try_from_rows(rows).unwrap();
+    /// assert_eq!(edges.num_rows(), 2);
+    /// ```
+    pub fn try_from_rows<I, L, R, P, C>(rows: I) -> Result<Self, TskitError>
+    where
+        I: IntoIterator<Item = (L, R, P, C)>,
+        L: Into<Position>,
+        R: Into<Position>,
+        P: Into<NodeId>,
+        C: Into<NodeId>,
+    {
+        let mut table = Self::default();
+        for (left, right, parent, child) in rows {
+            table.add_row(left, right, parent, child)?;
+        }
+        Ok(table)
+    }
+
+    /// Read a table written by [`EdgeTable::write_csv`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::OwningNodeTable::read_csv`].
+    pub fn read_csv<R: std::io::BufRead>(reader: R) -> Result<Self, TskitError> {
+        crate::table_csv::read_edges(reader)
+    }
+}
+
+/// # Panics
+///
+/// Panics if adding a row fails.
+/// See [`OwningEdgeTable::try_from_rows`] for a fallible alternative.
+impl<L, R, P, C> FromIterator<(L, R, P, C)> for OwningEdgeTable
+where
+    L: Into<Position>,
+    R: Into<Position>,
+    P: Into<NodeId>,
+    C: Into<NodeId>,
+{
+    fn from_iter<I: IntoIterator<Item = (L, R, P, C)>>(rows: I) -> Self {
+        Self::try_from_rows(rows).unwrap()
+    }
 }
@@ -47,6 +47,71 @@ impl From<crate::sys::Error> for TskitError {
     }
 }
 
+fn io_error_kind(code: i32) -> std::io::ErrorKind {
+    use crate::sys::bindings as ll_bindings;
+    use std::io::ErrorKind;
+
+    // SAFETY: these functions only inspect their argument
+    if code < 0 && unsafe { ll_bindings::tsk_is_kas_error(code) } {
+        return match unsafe { ll_bindings::tsk_get_kas_error(code) } {
+            ll_bindings::KAS_ERR_NO_MEMORY => ErrorKind::OutOfMemory,
+            ll_bindings::KAS_ERR_EOF => ErrorKind::UnexpectedEof,
+            ll_bindings::KAS_ERR_BAD_MODE | ll_bindings::KAS_ERR_BAD_FLAGS => {
+                ErrorKind::InvalidInput
+            }
+            ll_bindings::KAS_ERR_BAD_FILE_FORMAT
+            | ll_bindings::KAS_ERR_VERSION_TOO_OLD
+            | ll_bindings::KAS_ERR_VERSION_TOO_NEW
+            | ll_bindings::KAS_ERR_BAD_TYPE
+            | ll_bindings::KAS_ERR_KEY_NOT_FOUND
+            | ll_bindings::KAS_ERR_TYPE_MISMATCH => ErrorKind::InvalidData,
+            _ => ErrorKind::Other,
+        };
+    }
+    match code {
+        ll_bindings::TSK_ERR_NO_MEMORY => ErrorKind::OutOfMemory,
+        ll_bindings::TSK_ERR_EOF => ErrorKind::UnexpectedEof,
+        ll_bindings::TSK_ERR_FILE_FORMAT
+        | ll_bindings::TSK_ERR_FILE_VERSION_TOO_OLD
+        | ll_bindings::TSK_ERR_FILE_VERSION_TOO_NEW
+        | ll_bindings::TSK_ERR_REQUIRED_COL_NOT_FOUND
+        | ll_bindings::TSK_ERR_BOTH_COLUMNS_REQUIRED
+        | ll_bindings::TSK_ERR_BAD_COLUMN_TYPE => ErrorKind::InvalidData,
+        _ => ErrorKind::Other,
+    }
+}
+
+/// Convert into [`std::io::Error`], allowing `?` in functions
+/// returning [`std::io::Result`].
+///
+/// For [`TskitError::ErrorCode`], error codes related to
+/// file formats, end of file, and memory allocation are mapped
+/// to the corresponding [`std::io::ErrorKind`].
+/// All other errors have kind [`std::io::ErrorKind::Other`].
+///
+/// The original error is kept as the inner error.
+///
+/// # Examples
+///
+/// ```
+/// fn load(filename: &str) -> std::io::Result<tskit::TreeSequence> {
+///     let treeseq = tskit::TreeSequence::load(filename)?;
+///     Ok(treeseq)
+/// }
+///
+/// let error = load("not_a_file.trees").unwrap_err();
+/// assert!(error.get_ref().unwrap().is::<tskit::TskitError>());
+/// ```
+impl From<TskitError> for std::io::Error {
+    fn from(error: TskitError) -> Self {
+        let kind = match error {
+            TskitError::ErrorCode { code } => io_error_kind(code),
+            _ => std::io::ErrorKind::Other,
+        };
+        std::io::Error::new(kind, error)
+    }
+}
+
 /// Takes the return code from a tskit
 /// function and panics if the code indicates
 /// an error.  The error message is included
@@ -150,6 +215,25 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_into_io_error() {
+        let error = TskitError::ErrorCode {
+            code: crate::sys::bindings::TSK_ERR_FILE_FORMAT,
+        };
+        let message = error.to_string();
+        let io_error = std::io::Error::from(error);
+        assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(io_error.to_string(), message);
+
+        let io_error = std::io::Error::from(TskitError::ErrorCode { code: -202 });
+        assert_eq!(io_error.kind(), std::io::ErrorKind::Other);
+        assert!(io_error.to_string().contains("Node out of bounds"));
+
+        let io_error = std::io::Error::from(TskitError::IndexError);
+        assert_eq!(io_error.kind(), std::io::ErrorKind::Other);
+        assert_eq!(io_error.to_string(), "Invalid index");
+    }
+
     #[test]
     fn test_anyhow_compatability() {
         fn foo() -> anyhow::Result<crate::TableCollection> {
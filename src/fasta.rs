@@ -0,0 +1,128 @@
+use crate::TreeFlags;
+use crate::TreeSequence;
+use crate::TskitError;
+use streaming_iterator::StreamingIterator;
+
+/// Options for [`TreeSequence::write_fasta`].
+///
+/// # Examples
+///
+/// ```
+/// let options = tskit::FastaWriteOptions {
+///     line_width: None,
+///     ..Default::default()
+/// };
+/// assert!(options.reference_base.is_none());
+/// ```
+#[derive(Clone, Debug)]
+pub struct FastaWriteOptions {
+    /// The maximum number of characters per line of sequence.
+    /// If `None`, each sequence is written on a single line.
+    pub line_width: Option<usize>,
+    /// If `Some(base)`, write the full sequence, using `base`
+    /// at positions without a site.
+    /// If `None`, write only the states at sites.
+    pub reference_base: Option<u8>,
+    /// Record names, one per sample, in the order of
+    /// [`TreeSequence::sample_nodes`].
+    /// If `None`, samples are named `n<node id>`.
+    ///
+    /// To name records using individual metadata, see
+    /// [`TreeSequence::write_fasta_named_by`].
+    pub names: Option<Vec<String>>,
+}
+
+impl Default for FastaWriteOptions {
+    fn default() -> Self {
+        Self {
+            line_width: Some(60),
+            reference_base: None,
+            names: None,
+        }
+    }
+}
+
+fn io_error(error: std::io::Error) -> TskitError {
+    TskitError::LibraryError(error.to_string())
+}
+
+fn integer_coordinate(x: f64) -> Result<usize, TskitError> {
+    match x.fract() == 0.0 && x >= 0.0 {
+        true => Ok(x as usize),
+        false => Err(TskitError::ValueError {
+            got: x.to_string(),
+            expected: "integer coordinates when writing the full sequence".to_string(),
+        }),
+    }
+}
+
+pub(crate) fn write_fasta<W: std::io::Write>(
+    treeseq: &TreeSequence,
+    writer: &mut W,
+    options: FastaWriteOptions,
+) -> Result<(), TskitError> {
+    let samples = treeseq.sample_nodes();
+    let names = match options.names {
+        Some(names) if names.len() != samples.len() => {
+            return Err(TskitError::ValueError {
+                got: format!("{} names", names.len()),
+                expected: format!("{} names, one per sample", samples.len()),
+            })
+        }
+        Some(names) => names,
+        None => samples.iter().map(|s| format!("n{}", s)).collect(),
+    };
+
+    let mut sequences = match options.reference_base {
+        Some(base) => {
+            // SAFETY: the tree sequence pointer is not null
+            let length =
+                unsafe { crate::sys::bindings::tsk_treeseq_get_sequence_length(treeseq.as_ptr()) };
+            let length = integer_coordinate(length)?;
+            vec![vec![base; length]; samples.len()]
+        }
+        None => vec![vec![]; samples.len()],
+    };
+
+    let mut sites = treeseq.sites().iter().peekable();
+    let mut trees = treeseq.tree_iterator(TreeFlags::SAMPLE_LISTS)?;
+    while let Some(tree) = trees.next() {
+        let right = tree.interval().1;
+        while let Some(site) = sites.next_if(|site| site.position < right) {
+            let (states, node_state) = tree.site_states(site.id)?;
+            let index = match options.reference_base {
+                Some(_) => Some(integer_coordinate(site.position.into())?),
+                None => None,
+            };
+            for (sequence, sample) in sequences.iter_mut().zip(samples) {
+                let state = &states[node_state[sample.as_usize()]];
+                let base = match state.as_slice() {
+                    [base] => *base,
+                    _ => {
+                        return Err(TskitError::ValueError {
+                            got: String::from_utf8_lossy(state).to_string(),
+                            expected: "states of exactly one character".to_string(),
+                        })
+                    }
+                };
+                match index {
+                    Some(i) => sequence[i] = base,
+                    None => sequence.push(base),
+                }
+            }
+        }
+    }
+
+    for (name, sequence) in names.iter().zip(sequences.iter()) {
+        writeln!(writer, ">{}", name).map_err(io_error)?;
+        let width = match options.line_width {
+            Some(width) if width > 0 => width,
+            _ => sequence.len().max(1),
+        };
+        for line in sequence.chunks(width) {
+            writer.write_all(line).map_err(io_error)?;
+            writeln!(writer).map_err(io_error)?;
+        }
+    }
+    Ok(())
+}
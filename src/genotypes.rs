@@ -0,0 +1,231 @@
+use crate::NodeId;
+use crate::SiteId;
+use crate::TreeFlags;
+use crate::TreeSequence;
+use crate::TskitError;
+use streaming_iterator::StreamingIterator;
+
+/// Bit-packed genotypes for biallelic sites.
+///
+/// Each genotype is `false` for the ancestral state and
+/// `true` for the derived state.
+/// The genotypes of eight samples are stored per byte.
+///
+/// Samples are indexed by their position in
+/// [`TreeSequence::sample_nodes`].
+///
+/// See [`TreeSequence::packed_biallelic_genotypes`].
+#[derive(Clone, Debug)]
+pub struct PackedGenotypes {
+    num_sites: usize,
+    num_samples: usize,
+    bytes_per_site: usize,
+    data: Vec<u8>,
+}
+
+impl PackedGenotypes {
+    fn new(num_sites: usize, num_samples: usize) -> Self {
+        let bytes_per_site = (num_samples + 7) / 8;
+        Self {
+            num_sites,
+            num_samples,
+            bytes_per_site,
+            data: vec![0; num_sites * bytes_per_site],
+        }
+    }
+
+    fn set(&mut self, site: usize, sample: usize) {
+        self.data[site * self.bytes_per_site + sample / 8] |= 1 << (sample % 8);
+    }
+
+    /// Get the genotype of a sample at a site.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(true)` if the sample carries the derived state.
+    /// * `Some(false)` if the sample carries the ancestral state.
+    /// * `None` if `site` or `sample` is out of range.
+    pub fn get<S: Into<SiteId>>(&self, site: S, sample: usize) -> Option<bool> {
+        let site = site.into();
+        if site.is_null() || site.as_usize() >= self.num_sites || sample >= self.num_samples {
+            return None;
+        }
+        let byte = self.data[site.as_usize() * self.bytes_per_site + sample / 8];
+        Some(byte & (1 << (sample % 8)) != 0)
+    }
+
+    /// The number of sites.
+    pub fn num_sites(&self) -> usize {
+        self.num_sites
+    }
+
+    /// The number of samples.
+    pub fn num_samples(&self) -> usize {
+        self.num_samples
+    }
+
+    /// The packed genotypes of a site.
+    ///
+    /// The genotype of sample `i` is bit `i % 8` of byte `i / 8`.
+    ///
+    /// # Returns
+    ///
+    /// `None` if `site` is out of range.
+    pub fn site_bytes<S: Into<SiteId>>(&self, site: S) -> Option<&[u8]> {
+        let site = site.into();
+        if site.is_null() || site.as_usize() >= self.num_sites {
+            return None;
+        }
+        let start = site.as_usize() * self.bytes_per_site;
+        Some(&self.data[start..start + self.bytes_per_site])
+    }
+}
+
+pub(crate) fn packed_biallelic_genotypes(
+    treeseq: &TreeSequence,
+) -> Result<PackedGenotypes, TskitError> {
+    let samples = treeseq.sample_nodes();
+    let mut genotypes = PackedGenotypes::new(treeseq.sites().num_rows().as_usize(), samples.len());
+    let mut sites = treeseq.sites().iter().peekable();
+    let mut trees = treeseq.tree_iterator(TreeFlags::SAMPLE_LISTS)?;
+    while let Some(tree) = trees.next() {
+        let right = tree.interval().1;
+        while let Some(site) = sites.next_if(|site| site.position < right) {
+            let (states, node_state) = tree.site_states(site.id)?;
+            let ancestral = &states[0];
+            let derived = states.iter().find(|s| *s != ancestral);
+            if states.iter().any(|s| s != ancestral && Some(s) != derived) {
+                return Err(TskitError::ValueError {
+                    got: format!("more than two alleles at site {}", site.id),
+                    expected: "biallelic sites".to_string(),
+                });
+            }
+            for (i, sample) in samples.iter().enumerate() {
+                if &states[node_state[sample.as_usize()]] != ancestral {
+                    genotypes.set(site.id.as_usize(), i);
+                }
+            }
+        }
+    }
+    Ok(genotypes)
+}
+
+pub(crate) fn site_frequency_spectrum(
+    treeseq: &TreeSequence,
+    samples: &[NodeId],
+) -> Result<Vec<u64>, TskitError> {
+    if let Some(u) = samples.iter().find(|&&u| !treeseq.is_sample(u)) {
+        return Err(TskitError::ValueError {
+            got: format!("node {}", u),
+            expected: "sample nodes".to_string(),
+        });
+    }
+    let mut sfs = vec![0_u64; samples.len() + 1];
+    let mut sites = treeseq.sites().iter().peekable();
+    let mut trees = treeseq.tree_iterator(TreeFlags::SAMPLE_LISTS)?;
+    while let Some(tree) = trees.next() {
+        let right = tree.interval().1;
+        while let Some(site) = sites.next_if(|site| site.position < right) {
+            let (states, node_state) = tree.site_states(site.id)?;
+            let ancestral = &states[0];
+            let mut derived: Vec<&Vec<u8>> = vec![];
+            for state in states.iter().filter(|s| *s != ancestral) {
+                if !derived.contains(&state) {
+                    derived.push(state);
+                }
+            }
+            for state in derived {
+                let count = samples
+                    .iter()
+                    .filter(|u| &states[node_state[u.as_usize()]] == state)
+                    .count();
+                sfs[count] += 1;
+            }
+        }
+    }
+    Ok(sfs)
+}
+
+pub(crate) fn allele_frequencies_by_population(
+    treeseq: &TreeSequence,
+) -> Result<Vec<Vec<f64>>, TskitError> {
+    let num_populations = treeseq.populations().num_rows().as_usize();
+    let nodes = treeseq.nodes();
+    // The population of each sample, skipping samples without one.
+    let mut sample_populations = vec![];
+    let mut population_sizes = vec![0_usize; num_populations];
+    for &u in treeseq.sample_nodes() {
+        let population = nodes.population(u).unwrap_or(crate::PopulationId::NULL);
+        if population.is_null() {
+            continue;
+        }
+        let p = population.as_usize();
+        if p >= num_populations {
+            return Err(TskitError::IndexError);
+        }
+        population_sizes[p] += 1;
+        sample_populations.push((u, p));
+    }
+    let mut frequencies = vec![];
+    let mut sites = treeseq.sites().iter().peekable();
+    let mut trees = treeseq.tree_iterator(TreeFlags::SAMPLE_LISTS)?;
+    while let Some(tree) = trees.next() {
+        let right = tree.interval().1;
+        while let Some(site) = sites.next_if(|site| site.position < right) {
+            let (states, node_state) = tree.site_states(site.id)?;
+            let ancestral = &states[0];
+            let mut counts = vec![0_usize; num_populations];
+            for &(u, p) in &sample_populations {
+                if &states[node_state[u.as_usize()]] != ancestral {
+                    counts[p] += 1;
+                }
+            }
+            frequencies.push(
+                counts
+                    .iter()
+                    .zip(&population_sizes)
+                    .map(|(&count, &size)| match size {
+                        0 => f64::NAN,
+                        n => count as f64 / n as f64,
+                    })
+                    .collect(),
+            );
+        }
+    }
+    Ok(frequencies)
+}
+
+pub(crate) fn mutational_load(treeseq: &TreeSequence) -> Result<Vec<f64>, TskitError> {
+    let samples = treeseq.sample_nodes();
+    let positions = treeseq.sites().position_slice();
+    let mutations = treeseq.mutations();
+    // Mutations are sorted by site, and sites by position.
+    let mut mutation_nodes = mutations
+        .site_slice()
+        .iter()
+        .zip(mutations.node_slice())
+        .peekable();
+    let mut num_mutations = vec![0_u64; treeseq.nodes().num_rows().as_usize()];
+    let mut load = vec![0.0; samples.len()];
+    let mut trees = treeseq.tree_iterator(TreeFlags::NO_SAMPLE_COUNTS)?;
+    while let Some(tree) = trees.next() {
+        let right = tree.interval().1;
+        num_mutations.iter_mut().for_each(|n| *n = 0);
+        while let Some((_, node)) =
+            mutation_nodes.next_if(|(site, _)| positions[site.as_usize()] < right)
+        {
+            num_mutations[node.as_usize()] += 1;
+        }
+        let span = f64::from(tree.span());
+        for (sample_load, &sample) in load.iter_mut().zip(samples) {
+            let mut count = 0;
+            let mut u = sample;
+            while !u.is_null() {
+                count += num_mutations[u.as_usize()];
+                u = tree.parent(u).unwrap_or(NodeId::NULL);
+            }
+            *sample_load += count as f64 * span;
+        }
+    }
+    Ok(load)
+}
@@ -23,7 +23,31 @@ impl PartialEq for IndividualTableRow {
             && self.flags == other.flags
             && self.parents == other.parents
             && self.metadata == other.metadata
-            && self.location == other.location
+            && match (&self.location, &other.location) {
+                (Some(a), Some(b)) => {
+                    a.len() == b.len()
+                        && a.iter()
+                            .zip(b.iter())
+                            .all(|(&x, &y)| crate::util::hash_eq_f64(x, y))
+                }
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl Eq for IndividualTableRow {}
+
+impl std::hash::Hash for IndividualTableRow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.flags.hash(state);
+        self.parents.hash(state);
+        self.metadata.hash(state);
+        self.location.is_some().hash(state);
+        for &x in self.location.iter().flatten() {
+            crate::util::hash_f64(x, state);
+        }
     }
 }
 
@@ -48,6 +72,8 @@ impl<'a> IndividualTableRowView<'a> {
             metadata: None,
         }
     }
+
+    row_view_decode_metadata!(IndividualMetadata);
 }
 
 impl<'a> PartialEq for IndividualTableRowView<'a> {
@@ -137,6 +163,15 @@ impl Iterator for IndividualTableIterator {
     }
 }
 
+impl<'a> IntoIterator for &'a IndividualTable {
+    type Item = IndividualTableRow;
+    type IntoIter = IndividualTableRefIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        crate::table_iterator::make_table_iterator::<&IndividualTable>(self)
+    }
+}
+
 impl IndividualTable {
     pub(crate) fn new_from_table(
         individuals: *mut ll_bindings::tsk_individual_table_t,
@@ -186,6 +221,35 @@ impl IndividualTable {
         )
     }
 
+    /// Return the locations for a given row as a fixed-size array.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(location)` if `row` is valid and has exactly `N` locations.
+    /// * `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(1.).unwrap();
+    /// let i = tables.add_individual(0, &[1.0, 2.0, 3.0], None).unwrap();
+    ///
+    /// let location: [tskit::Location; 3] = tables.individuals().location_array(i).unwrap();
+    /// assert_eq!(location[0], 1.0);
+    /// assert_eq!(location[2], 3.0);
+    ///
+    /// // The wrong number of dimensions
+    /// assert!(tables.individuals().location_array::<_, 2>(i).is_none());
+    /// // Row out of range
+    /// assert!(tables.individuals().location_array::<_, 3>(1).is_none());
+    /// ```
+    pub fn location_array<I: Into<IndividualId> + Copy, const N: usize>(
+        &self,
+        row: I,
+    ) -> Option<[Location; N]> {
+        self.location(row)?.try_into().ok()
+    }
+
     /// Return the parents for a given row.
     ///
     /// # Returns
@@ -202,6 +266,44 @@ impl IndividualTable {
         )
     }
 
+    /// Return the non-null parents for a given row.
+    ///
+    /// Unlike [`IndividualTable::parents`], entries equal to
+    /// [`IndividualId::NULL`] are removed.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(parents)` if `row` is valid and has parents recorded.
+    ///   The vector is empty if all recorded parents are null.
+    /// * `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(1.).unwrap();
+    /// let parent = tables.add_individual(0, None, None).unwrap();
+    /// let child = tables
+    ///     .add_individual(0, None, &[parent, tskit::IndividualId::NULL])
+    ///     .unwrap();
+    /// let orphan = tables
+    ///     .add_individual(0, None, &[tskit::IndividualId::NULL])
+    ///     .unwrap();
+    ///
+    /// // No parents are recorded
+    /// assert!(tables.individuals().parent_individuals(parent).is_none());
+    /// // Null parents are removed
+    /// assert_eq!(tables.individuals().parent_individuals(child), Some(vec![parent]));
+    /// // A null parent is recorded
+    /// assert_eq!(tables.individuals().parent_individuals(orphan), Some(vec![]));
+    /// ```
+    pub fn parent_individuals<I: Into<IndividualId> + Copy>(
+        &self,
+        row: I,
+    ) -> Option<Vec<IndividualId>> {
+        self.parents(row)
+            .map(|p| p.iter().copied().filter(|p| !p.is_null()).collect())
+    }
+
     /// Return the metadata for a given row.
     ///
     /// # Returns
@@ -434,6 +536,19 @@ match tables.individuals().metadata::<MutationMetadata>(0.into())
     build_table_column_slice_getter!(
         /// Get the flags column as a slice
         => flags, flags_slice_raw, ll_bindings::tsk_flags_t);
+
+    /// Write the table as comma-separated values.
+    ///
+    /// See [`crate::NodeTable::write_csv`] for the format.
+    ///
+    /// The values of the location and parents columns are separated by `;`.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::LibraryError`] if writing fails.
+    pub fn write_csv<W: std::io::Write>(&self, writer: &mut W) -> Result<(), TskitError> {
+        crate::table_csv::write_individuals(self, writer)
+    }
 }
 
 build_owned_table_type!(
@@ -492,4 +607,56 @@ build_owned_table_type!(
 impl OwningIndividualTable {
     individual_table_add_row!(=> add_row, self, self.as_mut_ptr());
     individual_table_add_row_with_metadata!(=> add_row_with_metadata, self, self.as_mut_ptr());
+
+    /// Build a table from an iterator over
+    /// `(flags, location, parents)` tuples.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by [`OwningIndividualTable::add_row`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rows = vec![(0, [0.5, 1.0], [-1, -1]), (0, [1.5, 2.0], [0, -1])];
+    /// let individuals = tskit::OwningIndividualTable::try_from_rows(rows).unwrap();
+    /// assert_eq!(individuals.num_rows(), 2);
+    /// ```
+    pub fn try_from_rows<I, F, L, P>(rows: I) -> Result<Self, TskitError>
+    where
+        I: IntoIterator<Item = (F, L, P)>,
+        F: Into<IndividualFlags>,
+        L: crate::IndividualLocation,
+        P: crate::IndividualParents,
+    {
+        let mut table = Self::default();
+        for (flags, location, parents) in rows {
+            table.add_row(flags, location, parents)?;
+        }
+        Ok(table)
+    }
+
+    /// Read a table written by [`IndividualTable::write_csv`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::OwningNodeTable::read_csv`].
+    pub fn read_csv<R: std::io::BufRead>(reader: R) -> Result<Self, TskitError> {
+        crate::table_csv::read_individuals(reader)
+    }
+}
+
+/// # Panics
+///
+/// Panics if adding a row fails.
+/// See [`OwningIndividualTable::try_from_rows`] for a fallible alternative.
+impl<F, L, P> FromIterator<(F, L, P)> for OwningIndividualTable
+where
+    F: Into<IndividualFlags>,
+    L: crate::IndividualLocation,
+    P: crate::IndividualParents,
+{
+    fn from_iter<I: IntoIterator<Item = (F, L, P)>>(rows: I) -> Self {
+        Self::try_from_rows(rows).unwrap()
+    }
 }
@@ -78,13 +78,17 @@
 pub use sys::bindings;
 
 mod _macros; // Starts w/_ to be sorted at front by rustfmt!
+mod concatenate;
 mod edge_differences;
 mod edge_table;
 pub mod error;
+mod fasta;
+mod genotypes;
 mod individual_table;
 pub mod metadata;
 mod migration_table;
 mod mutation_table;
+mod newick;
 mod newtypes;
 mod node_table;
 mod population_table;
@@ -92,6 +96,8 @@ pub mod prelude;
 mod site_table;
 mod sys;
 mod table_collection;
+mod table_csv;
+mod table_diff;
 mod table_iterator;
 mod table_views;
 mod traits;
@@ -99,10 +105,13 @@ mod tree_interface;
 mod trees;
 pub mod types;
 mod util;
+mod validating_table_collection;
 
 pub use edge_differences::*;
 pub use edge_table::{EdgeTable, EdgeTableRow, OwningEdgeTable};
 pub use error::TskitError;
+pub use fasta::FastaWriteOptions;
+pub use genotypes::PackedGenotypes;
 pub use individual_table::{IndividualTable, IndividualTableRow, OwningIndividualTable};
 pub use migration_table::{MigrationTable, MigrationTableRow, OwningMigrationTable};
 pub use mutation_table::{MutationTable, MutationTableRow, OwningMutationTable};
@@ -114,10 +123,12 @@ pub use population_table::{OwningPopulationTable, PopulationTable, PopulationTab
 pub use site_table::{OwningSiteTable, SiteTable, SiteTableRow};
 pub use sys::flags::*;
 pub use table_collection::TableCollection;
+pub use table_diff::{FloatTolerance, TableDiff};
 pub use traits::IndividualLocation;
 pub use traits::IndividualParents;
 pub use tree_interface::{NodeTraversalOrder, TreeInterface};
-pub use trees::{Tree, TreeSequence};
+pub use trees::{NodeIdMap, Tree, TreeSequence, TreeSequenceSummary};
+pub use validating_table_collection::ValidatingTableCollection;
 
 // Optional features
 #[cfg(feature = "provenance")]
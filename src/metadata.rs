@@ -94,6 +94,24 @@
 //! # }
 //! ```
 //!
+//! The `"bincode"` serializer uses the 1.x API of `bincode`.
+//! For `bincode` 2.x, use `#[serializer("bincode2")]`, which encodes
+//! and decodes via `bincode::serde` using `bincode::config::standard()`.
+//! Your package must depend on `bincode` 2.x, with its `serde` feature
+//! enabled, under the name `bincode`.
+//! The two encodings are not compatible.
+//!
+//! The derive macros require the `serde` traits.
+//! Forgetting them is an error, reported at the name of the type:
+//!
+//! ```compile_fail
+//! #[derive(tskit::metadata::NodeMetadata)]
+//! #[serializer("serde_json")]
+//! struct NotSerializable {
+//!     value: i32,
+//! }
+//! ```
+//!
 //! ## Example: manual implementation of all of the traits.
 //!
 //! Okay, let's do things the hard way.
@@ -238,6 +256,31 @@ impl EncodedMetadata {
     }
 }
 
+/// JSON metadata schemas for the tables of a
+/// [`TableCollection`](crate::TableCollection).
+///
+/// A value of `None` refers to a table whose schema
+/// is not being set or is empty.
+///
+/// See [`TableCollection::set_all_json_metadata_schemas`](crate::TableCollection::set_all_json_metadata_schemas).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MetadataSchemaSet {
+    /// Schema for the edge table.
+    pub edges: Option<String>,
+    /// Schema for the individual table.
+    pub individuals: Option<String>,
+    /// Schema for the migration table.
+    pub migrations: Option<String>,
+    /// Schema for the mutation table.
+    pub mutations: Option<String>,
+    /// Schema for the node table.
+    pub nodes: Option<String>,
+    /// Schema for the population table.
+    pub populations: Option<String>,
+    /// Schema for the site table.
+    pub sites: Option<String>,
+}
+
 #[derive(Error, Debug)]
 #[non_exhaustive]
 pub enum MetadataError {
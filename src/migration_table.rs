@@ -27,13 +27,28 @@ impl PartialEq for MigrationTableRow {
             && self.node == other.node
             && self.source == other.source
             && self.dest == other.dest
-            && crate::util::partial_cmp_equal(&self.left, &other.left)
-            && crate::util::partial_cmp_equal(&self.right, &other.right)
-            && crate::util::partial_cmp_equal(&self.time, &other.time)
+            && crate::util::hash_eq_f64(self.left, other.left)
+            && crate::util::hash_eq_f64(self.right, other.right)
+            && crate::util::hash_eq_f64(self.time, other.time)
             && self.metadata == other.metadata
     }
 }
 
+impl Eq for MigrationTableRow {}
+
+impl std::hash::Hash for MigrationTableRow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.node.hash(state);
+        self.source.hash(state);
+        self.dest.hash(state);
+        crate::util::hash_f64(self.left, state);
+        crate::util::hash_f64(self.right, state);
+        crate::util::hash_f64(self.time, state);
+        self.metadata.hash(state);
+    }
+}
+
 fn make_migration_table_row(table: &MigrationTable, pos: tsk_id_t) -> Option<MigrationTableRow> {
     Some(MigrationTableRow {
         id: pos.into(),
@@ -71,6 +86,15 @@ impl Iterator for MigrationTableIterator {
     }
 }
 
+impl<'a> IntoIterator for &'a MigrationTable {
+    type Item = MigrationTableRow;
+    type IntoIter = MigrationTableRefIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        crate::table_iterator::make_table_iterator::<&MigrationTable>(self)
+    }
+}
+
 #[derive(Debug)]
 pub struct MigrationTableRowView<'a> {
     table: &'a MigrationTable,
@@ -98,6 +122,8 @@ impl<'a> MigrationTableRowView<'a> {
             metadata: None,
         }
     }
+
+    row_view_decode_metadata!(MigrationMetadata);
 }
 
 impl<'a> PartialEq for MigrationTableRowView<'a> {
@@ -366,6 +392,17 @@ impl MigrationTable {
     build_table_column_slice_getter!(
         /// Get the dest column as a slice
         => dest, dest_slice_raw, ll_bindings::tsk_id_t);
+
+    /// Write the table as comma-separated values.
+    ///
+    /// See [`crate::NodeTable::write_csv`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::LibraryError`] if writing fails.
+    pub fn write_csv<W: std::io::Write>(&self, writer: &mut W) -> Result<(), TskitError> {
+        crate::table_csv::write_migrations(self, writer)
+    }
 }
 
 build_owned_table_type!(
@@ -423,4 +460,86 @@ build_owned_table_type!(
 impl OwningMigrationTable {
     migration_table_add_row!(=> add_row, self, self.as_mut_ptr());
     migration_table_add_row_with_metadata!(=> add_row_with_metadata, self, self.as_mut_ptr());
+    migration_table_add_mass_migration!(
+    /// Add a row recording the migration of a node's whole span.
+    ///
+    /// This is [`OwningMigrationTable::add_row`] with the arguments
+    /// in the order used to describe a mass migration event.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`](crate::TskitError::ValueError) if `source`
+    /// and `dest` are the same population.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut migrations = tskit::OwningMigrationTable::default();
+    /// let rowid = migrations.add_mass_migration(10.3, 0, 1, (0., 100.), 2).unwrap();
+    /// assert_eq!(migrations.source(rowid), Some(0.into()));
+    /// assert_eq!(migrations.dest(rowid), Some(1.into()));
+    /// assert_eq!(migrations.node(rowid), Some(2.into()));
+    /// assert_eq!(migrations.left(rowid), Some(0.0.into()));
+    /// assert_eq!(migrations.right(rowid), Some(100.0.into()));
+    /// assert_eq!(migrations.time(rowid), Some(10.3.into()));
+    /// ```
+    => add_mass_migration, self, self.as_mut_ptr());
+
+    /// Build a table from an iterator over
+    /// `((left, right), node, (source, dest), time)` tuples.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by [`OwningMigrationTable::add_row`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rows = vec![((0., 1.), 1, (0, 1), 10.3), ((1., 2.), 1, (1, 0), 12.0)];
+    /// let migrations = tskit::OwningMigrationTable::try_from_rows(rows).unwrap();
+    /// assert_eq!(migrations.num_rows(), 2);
+    /// ```
+    pub fn try_from_rows<I, L, R, N, S, D, T>(rows: I) -> Result<Self, TskitError>
+    where
+        I: IntoIterator<Item = ((L, R), N, (S, D), T)>,
+        L: Into<Position>,
+        R: Into<Position>,
+        N: Into<NodeId>,
+        S: Into<PopulationId>,
+        D: Into<PopulationId>,
+        T: Into<Time>,
+    {
+        let mut table = Self::default();
+        for (span, node, source_dest, time) in rows {
+            table.add_row(span, node, source_dest, time)?;
+        }
+        Ok(table)
+    }
+
+    /// Read a table written by [`MigrationTable::write_csv`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::OwningNodeTable::read_csv`].
+    pub fn read_csv<R: std::io::BufRead>(reader: R) -> Result<Self, TskitError> {
+        crate::table_csv::read_migrations(reader)
+    }
+}
+
+/// # Panics
+///
+/// Panics if adding a row fails.
+/// See [`OwningMigrationTable::try_from_rows`] for a fallible alternative.
+impl<L, R, N, S, D, T> FromIterator<((L, R), N, (S, D), T)> for OwningMigrationTable
+where
+    L: Into<Position>,
+    R: Into<Position>,
+    N: Into<NodeId>,
+    S: Into<PopulationId>,
+    D: Into<PopulationId>,
+    T: Into<Time>,
+{
+    fn from_iter<I: IntoIterator<Item = ((L, R), N, (S, D), T)>>(rows: I) -> Self {
+        Self::try_from_rows(rows).unwrap()
+    }
 }
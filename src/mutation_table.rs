@@ -19,18 +19,44 @@ pub struct MutationTableRow {
     pub metadata: Option<Vec<u8>>,
 }
 
+impl MutationTableRow {
+    /// Get the derived state as a [`str`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` if there is a derived state and it is valid UTF-8.
+    /// * `None` otherwise.
+    pub fn derived_state_str(&self) -> Option<&str> {
+        std::str::from_utf8(self.derived_state.as_ref()?).ok()
+    }
+}
+
 impl PartialEq for MutationTableRow {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
             && self.site == other.site
             && self.node == other.node
             && self.parent == other.parent
-            && crate::util::partial_cmp_equal(&self.time, &other.time)
+            && crate::util::hash_eq_f64(self.time, other.time)
             && self.derived_state == other.derived_state
             && self.metadata == other.metadata
     }
 }
 
+impl Eq for MutationTableRow {}
+
+impl std::hash::Hash for MutationTableRow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.site.hash(state);
+        self.node.hash(state);
+        self.parent.hash(state);
+        crate::util::hash_f64(self.time, state);
+        self.derived_state.hash(state);
+        self.metadata.hash(state);
+    }
+}
+
 fn make_mutation_table_row(table: &MutationTable, pos: tsk_id_t) -> Option<MutationTableRow> {
     let index = ll_bindings::tsk_size_t::try_from(pos).ok()?;
     match index {
@@ -74,6 +100,15 @@ impl Iterator for MutationTableIterator {
     }
 }
 
+impl<'a> IntoIterator for &'a MutationTable {
+    type Item = MutationTableRow;
+    type IntoIter = MutationTableRefIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        crate::table_iterator::make_table_iterator::<&MutationTable>(self)
+    }
+}
+
 #[derive(Debug)]
 pub struct MutationTableRowView<'a> {
     table: &'a MutationTable,
@@ -99,6 +134,8 @@ impl<'a> MutationTableRowView<'a> {
             metadata: None,
         }
     }
+
+    row_view_decode_metadata!(MutationMetadata);
 }
 
 impl<'a> PartialEq for MutationTableRowView<'a> {
@@ -215,6 +252,43 @@ impl MutationTable {
         )
     }
 
+    /// Return an iterator over mutation `m` and its ancestral mutations.
+    ///
+    /// The iterator yields `m`, then its parent, grandparent, etc.,
+    /// stopping when the parent is [`MutationId::NULL`].
+    ///
+    /// If `m` is out of range, the iterator is empty.
+    ///
+    /// # Note
+    ///
+    /// A valid chain cannot be longer than the number of rows in
+    /// the table.
+    /// If the parent column contains a cycle, the iterator terminates
+    /// after yielding that many values.
+    /// If a parent id is out of range, the iterator terminates
+    /// without yielding it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// let m0 = tables.add_mutation(0, 0, -1, 3.0, None).unwrap();
+    /// let m1 = tables.add_mutation(0, 0, m0, 2.0, None).unwrap();
+    /// let m2 = tables.add_mutation(0, 0, m1, 1.0, None).unwrap();
+    /// let chain = tables.mutations().parent_chain(m2).collect::<Vec<_>>();
+    /// assert_eq!(chain, [m2, m1, m0]);
+    /// ```
+    pub fn parent_chain<M: Into<MutationId>>(&self, m: M) -> impl Iterator<Item = MutationId> + '_ {
+        let m = m.into();
+        let first = self.parent(m).map(|_| m);
+        // A parent is only yielded if it is a row of the table,
+        // which also excludes MutationId::NULL.
+        std::iter::successors(first, move |&m| {
+            self.parent(m).filter(|&p| self.parent(p).is_some())
+        })
+        .take(self.num_rows().as_usize())
+    }
+
     /// Return the ``time`` value from row ``row`` of the table.
     ///
     /// # Errors
@@ -245,6 +319,37 @@ impl MutationTable {
         )
     }
 
+    /// Get the ``derived_state`` value from row ``row`` of the table
+    /// as a [`str`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` if `row` is valid, has a derived state,
+    ///   and the derived state is valid UTF-8.
+    /// * `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// let site = tables.add_site(1., Some(b"G")).unwrap();
+    /// let mutation = tables
+    ///     .add_mutation(site, 0, tskit::MutationId::NULL, 0.0, Some(b"A"))
+    ///     .unwrap();
+    /// assert_eq!(tables.mutations().derived_state_str(mutation), Some("A"));
+    /// let row = tables.mutations().row(mutation).unwrap();
+    /// assert_eq!(row.derived_state_str(), Some("A"));
+    ///
+    /// // Invalid UTF-8
+    /// let mutation = tables
+    ///     .add_mutation(site, 0, tskit::MutationId::NULL, 0.0, Some(&[0xff]))
+    ///     .unwrap();
+    /// assert!(tables.mutations().derived_state_str(mutation).is_none());
+    /// ```
+    pub fn derived_state_str<M: Into<MutationId>>(&self, row: M) -> Option<&str> {
+        std::str::from_utf8(self.derived_state(row)?).ok()
+    }
+
     /// Retrieve decoded metadata for a `row`.
     ///
     /// # Returns
@@ -332,16 +437,81 @@ impl MutationTable {
         => site, site_slice_raw, crate::sys::bindings::tsk_id_t);
     build_table_column_slice_getter!(
         /// Get the time column as a slice
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+        /// tables.add_mutation(0, 0, -1, 2.0, None).unwrap();
+        /// tables.add_mutation(0, 0, 0, 3.0, None).unwrap();
+        /// let oldest = tables
+        ///     .mutations()
+        ///     .time_slice()
+        ///     .iter()
+        ///     .fold(f64::NEG_INFINITY, |a, &t| a.max(t.into()));
+        /// assert_eq!(oldest, 3.0);
+        /// ```
         => time, time_slice, Time);
     build_table_column_slice_getter!(
         /// Get the time column as a slice
         => time, time_slice_raw, f64);
+    build_table_column_slice_mut_getter!(
+    /// Get the time column as a mutable slice
+    ///
+    /// # Examples
+    ///
+    /// For a [`crate::TableCollection`], accessing the table creates a temporary
+    /// that will be dropped, causing this code to not compile:
+    ///
+    /// ```compile_fail
+    /// # let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// # tables.add_mutation(0, 0, -1, 10.0, None).unwrap();
+    /// let time = tables.mutations().time_slice_mut();
+    /// println!("{}", time.len()); // ERROR: the temporary mutation table is dropped by now
+    /// ```
+    ///
+    /// Treating the returned slice as an iterable succeeds:
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// # tables.add_mutation(0, 0, -1, 10.0, None).unwrap();
+    /// for time in tables.mutations_mut().time_slice_mut() {
+    ///     *time = 55.0.into(); // change each mutation's time value
+    /// }
+    /// assert!(tables.mutations().time_slice().iter().all(|t| t == &55.0));
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// Unknown times are `NaN` values with a specific bit pattern,
+    /// so they must not be changed by arithmetic.
+    ///
+    /// # Panics
+    ///
+    /// Internally, we rely on a conversion of u64 to usize.
+    /// This conversion is fallible on some platforms.
+    /// If the conversion fails, this function will panic.
+        => time, time_slice_mut, Time);
+    build_table_column_slice_mut_getter!(
+        /// Get the time column as a mutable slice
+        => time, time_slice_raw_mut, f64);
     build_table_column_slice_getter!(
         /// Get the parent column as a slice
         => parent, parent_slice, MutationId);
     build_table_column_slice_getter!(
         /// Get the parent column as a slice
         => parent, parent_slice_raw, crate::sys::bindings::tsk_id_t);
+
+    /// Write the table as comma-separated values.
+    ///
+    /// See [`crate::NodeTable::write_csv`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::LibraryError`] if writing fails.
+    pub fn write_csv<W: std::io::Write>(&self, writer: &mut W) -> Result<(), TskitError> {
+        crate::table_csv::write_mutations(self, writer)
+    }
 }
 
 build_owned_table_type!(
@@ -398,4 +568,60 @@ build_owned_table_type!(
 impl OwningMutationTable {
     mutation_table_add_row!(=> add_row, self, self.as_mut_ptr());
     mutation_table_add_row_with_metadata!(=> add_row_with_metadata, self, self.as_mut_ptr());
+
+    /// Build a table from an iterator over
+    /// `(site, node, parent, time, derived_state)` tuples.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by [`OwningMutationTable::add_row`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rows = vec![(0, 1, -1, 1.0, Some(b"T".as_slice())), (0, 2, 0, 0.5, None)];
+    /// let mutations = tskit::OwningMutationTable::try_from_rows(rows).unwrap();
+    /// assert_eq!(mutations.num_rows(), 2);
+    /// ```
+    pub fn try_from_rows<'a, I, S, N, P, T>(rows: I) -> Result<Self, TskitError>
+    where
+        I: IntoIterator<Item = (S, N, P, T, Option<&'a [u8]>)>,
+        S: Into<SiteId>,
+        N: Into<NodeId>,
+        P: Into<MutationId>,
+        T: Into<Time>,
+    {
+        let mut table = Self::default();
+        for (site, node, parent, time, derived_state) in rows {
+            table.add_row(site, node, parent, time, derived_state)?;
+        }
+        Ok(table)
+    }
+
+    /// Read a table written by [`MutationTable::write_csv`].
+    ///
+    /// All `NaN` times are read as unknown times.
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::OwningNodeTable::read_csv`].
+    pub fn read_csv<R: std::io::BufRead>(reader: R) -> Result<Self, TskitError> {
+        crate::table_csv::read_mutations(reader)
+    }
+}
+
+/// # Panics
+///
+/// Panics if adding a row fails.
+/// See [`OwningMutationTable::try_from_rows`] for a fallible alternative.
+impl<'a, S, N, P, T> FromIterator<(S, N, P, T, Option<&'a [u8]>)> for OwningMutationTable
+where
+    S: Into<SiteId>,
+    N: Into<NodeId>,
+    P: Into<MutationId>,
+    T: Into<Time>,
+{
+    fn from_iter<I: IntoIterator<Item = (S, N, P, T, Option<&'a [u8]>)>>(rows: I) -> Self {
+        Self::try_from_rows(rows).unwrap()
+    }
 }
@@ -0,0 +1,241 @@
+use crate::NodeFlags;
+use crate::NodeId;
+use crate::Position;
+use crate::TableCollection;
+use crate::TableSortOptions;
+use crate::TskitError;
+
+struct NewickNode {
+    parent: Option<usize>,
+    children: Vec<usize>,
+    branch_length: Option<f64>,
+}
+
+struct Parser<'a> {
+    newick: &'a str,
+    pos: usize,
+    // Nodes in preorder
+    nodes: Vec<NewickNode>,
+    postorder: Vec<usize>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(newick: &'a str) -> Self {
+        Self {
+            newick,
+            pos: 0,
+            nodes: vec![],
+            postorder: vec![],
+        }
+    }
+
+    fn error(&self, expected: &str) -> TskitError {
+        let got = match self.peek() {
+            Some(c) => format!("{:?} at position {}", c, self.pos),
+            None => "end of input".to_string(),
+        };
+        TskitError::ValueError {
+            got,
+            expected: expected.to_string(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.newick[self.pos..].chars().next()
+    }
+
+    fn advance(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().map_or(false, char::is_whitespace) {
+            self.advance();
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), TskitError> {
+        self.skip_whitespace();
+        if self.peek() != Some(c) {
+            return Err(self.error(&format!("{:?}", c)));
+        }
+        self.advance();
+        Ok(())
+    }
+
+    // Labels are not used, so we only need to skip over them.
+    fn skip_label(&mut self) -> Result<(), TskitError> {
+        self.skip_whitespace();
+        if self.peek() == Some('\'') {
+            self.advance();
+            while self.peek().map_or(false, |c| c != '\'') {
+                self.advance();
+            }
+            return self.expect('\'');
+        }
+        while self
+            .peek()
+            .map_or(false, |c| !c.is_whitespace() && !"(),:;".contains(c))
+        {
+            self.advance();
+        }
+        Ok(())
+    }
+
+    fn parse_branch_length(&mut self) -> Result<Option<f64>, TskitError> {
+        self.skip_whitespace();
+        if self.peek() != Some(':') {
+            return Ok(None);
+        }
+        self.advance();
+        self.skip_whitespace();
+        let start = self.pos;
+        while self
+            .peek()
+            .map_or(false, |c| c.is_ascii_digit() || "+-.eE".contains(c))
+        {
+            self.advance();
+        }
+        match self.newick[start..self.pos].parse::<f64>() {
+            Ok(length) => Ok(Some(length)),
+            Err(_) => {
+                self.pos = start;
+                Err(self.error("a branch length"))
+            }
+        }
+    }
+
+    fn push_node(&mut self, parent: Option<usize>) -> usize {
+        let node = self.nodes.len();
+        self.nodes.push(NewickNode {
+            parent,
+            children: vec![],
+            branch_length: None,
+        });
+        if let Some(parent) = parent {
+            self.nodes[parent].children.push(node);
+        }
+        node
+    }
+
+    fn finish_node(&mut self, node: usize) -> Result<(), TskitError> {
+        self.skip_label()?;
+        self.nodes[node].branch_length = self.parse_branch_length()?;
+        self.postorder.push(node);
+        Ok(())
+    }
+
+    // The tree is parsed without recursion, so that the
+    // depth of nesting is not limited by the call stack.
+    fn parse_tree(&mut self) -> Result<(), TskitError> {
+        // Nodes whose children are being parsed
+        let mut open: Vec<usize> = vec![];
+        loop {
+            let node = self.push_node(open.last().copied());
+            self.skip_whitespace();
+            if self.peek() == Some('(') {
+                self.advance();
+                open.push(node);
+                continue;
+            }
+            self.finish_node(node)?;
+            loop {
+                let parent = match open.last() {
+                    Some(&parent) => parent,
+                    None => return Ok(()),
+                };
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(',') => {
+                        self.advance();
+                        break;
+                    }
+                    Some(')') => {
+                        self.advance();
+                        open.pop();
+                        self.finish_node(parent)?;
+                    }
+                    _ => return Err(self.error("',' or ')'")),
+                }
+            }
+        }
+    }
+
+    fn parse(mut self) -> Result<(Vec<NewickNode>, Vec<usize>), TskitError> {
+        self.parse_tree()?;
+        self.expect(';')?;
+        self.skip_whitespace();
+        if self.peek().is_some() {
+            return Err(self.error("end of input"));
+        }
+        Ok((self.nodes, self.postorder))
+    }
+}
+
+pub(crate) fn tables_from_newick(
+    newick: &str,
+    sequence_length: Position,
+) -> Result<TableCollection, TskitError> {
+    let (nodes, postorder) = Parser::new(newick).parse()?;
+
+    // Parents precede their children in the preorder.
+    let mut depth = vec![0.0; nodes.len()];
+    for (u, node) in nodes.iter().enumerate() {
+        if let Some(parent) = node.parent {
+            match node.branch_length {
+                Some(length) if length > 0.0 && length.is_finite() => {
+                    depth[u] = depth[parent] + length;
+                }
+                Some(length) => {
+                    return Err(TskitError::ValueError {
+                        got: length.to_string(),
+                        expected: "branch length > 0.0".to_string(),
+                    })
+                }
+                None => {
+                    return Err(TskitError::ValueError {
+                        got: "missing branch length".to_string(),
+                        expected: "a branch length for each non-root node".to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    // Tips get the first ids, in left to right order.
+    // Internal nodes follow in postorder.
+    let mut order = (0..nodes.len())
+        .filter(|&u| nodes[u].children.is_empty())
+        .collect::<Vec<_>>();
+    order.extend(
+        postorder
+            .into_iter()
+            .filter(|&u| !nodes[u].children.is_empty()),
+    );
+
+    let max_depth = depth.iter().copied().fold(0.0, f64::max);
+    let mut tables = TableCollection::new(sequence_length)?;
+    let mut ids = vec![NodeId::NULL; nodes.len()];
+    for &u in &order {
+        let flags = match nodes[u].children.is_empty() {
+            true => NodeFlags::new_sample(),
+            false => NodeFlags::default(),
+        };
+        ids[u] = tables.add_node(
+            flags,
+            max_depth - depth[u],
+            crate::PopulationId::NULL,
+            crate::IndividualId::NULL,
+        )?;
+    }
+    for &u in &order {
+        if let Some(parent) = nodes[u].parent {
+            tables.add_edge(0., sequence_length, ids[parent], ids[u])?;
+        }
+    }
+    tables.full_sort(TableSortOptions::default())?;
+    tables.build_index()?;
+    Ok(tables)
+}
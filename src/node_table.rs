@@ -125,11 +125,24 @@ impl PartialEq for NodeTableRow {
             && self.flags == other.flags
             && self.population == other.population
             && self.individual == other.individual
-            && crate::util::partial_cmp_equal(&self.time, &other.time)
+            && crate::util::hash_eq_f64(self.time, other.time)
             && self.metadata == other.metadata
     }
 }
 
+impl Eq for NodeTableRow {}
+
+impl std::hash::Hash for NodeTableRow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.flags.hash(state);
+        self.population.hash(state);
+        self.individual.hash(state);
+        crate::util::hash_f64(self.time, state);
+        self.metadata.hash(state);
+    }
+}
+
 fn make_node_table_row(table: &NodeTable, pos: tsk_id_t) -> Option<NodeTableRow> {
     Some(NodeTableRow {
         id: pos.into(),
@@ -164,6 +177,35 @@ impl Iterator for NodeTableIterator {
     }
 }
 
+/// Iterate over rows of the table.
+///
+/// # Examples
+///
+/// ```
+/// let mut tables = tskit::TableCollection::new(10.).unwrap();
+/// tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+/// tables.add_node(0, 2.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+/// let mut num_rows = 0;
+/// for row in tables.nodes() {
+///     assert_eq!(row.id, num_rows);
+///     num_rows += 1;
+/// }
+/// assert_eq!(num_rows, 2);
+///
+/// let nodes = tables.nodes();
+/// for row in nodes {
+///     assert!(row.time > 0.0);
+/// }
+/// ```
+impl<'a> IntoIterator for &'a NodeTable {
+    type Item = NodeTableRow;
+    type IntoIter = NodeTableRefIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        crate::table_iterator::make_table_iterator::<&NodeTable>(self)
+    }
+}
+
 #[derive(Debug)]
 pub struct NodeTableRowView<'a> {
     table: &'a NodeTable,
@@ -187,6 +229,37 @@ impl<'a> NodeTableRowView<'a> {
             metadata: None,
         }
     }
+
+    row_view_decode_metadata!(
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # #[cfg(feature = "derive")] {
+        /// use streaming_iterator::StreamingIterator;
+        ///
+        /// #[derive(serde::Serialize, serde::Deserialize, tskit::metadata::NodeMetadata)]
+        /// #[serializer("serde_json")]
+        /// struct Md {
+        ///     x: i32,
+        /// }
+        ///
+        /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+        /// for x in 0..3 {
+        ///     tables
+        ///         .add_node_with_metadata(0, 0.0, -1, -1, &Md { x })
+        ///         .unwrap();
+        /// }
+        /// let mut rows = tables.nodes().lending_iter();
+        /// while let Some(row) = rows.next() {
+        ///     if row.id == 1 {
+        ///         let decoded = row.decode_metadata::<Md>().unwrap().unwrap();
+        ///         assert_eq!(decoded.x, 1);
+        ///     }
+        /// }
+        /// # }
+        /// ```
+        NodeMetadata);
 }
 
 impl<'a> PartialEq for NodeTableRowView<'a> {
@@ -730,6 +803,36 @@ impl NodeTable {
             .collect::<Vec<_>>()
     }
 
+    /// Return the minimum and maximum node times.
+    ///
+    /// Times that are not finite, such as NaN, are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((min, max))` if any node has a finite time.
+    /// * `None` if the table is empty or no node has a finite time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut nodes = tskit::OwningNodeTable::default();
+    /// assert!(nodes.time_bounds().is_none());
+    /// for time in [5.0, 0.0, f64::NAN, 10.0] {
+    ///     nodes.add_row(0, time, -1, -1).unwrap();
+    /// }
+    /// assert_eq!(nodes.time_bounds(), Some((0.0.into(), 10.0.into())));
+    /// ```
+    pub fn time_bounds(&self) -> Option<(Time, Time)> {
+        self.time_slice_raw()
+            .iter()
+            .filter(|t| t.is_finite())
+            .fold(None, |bounds, &t| match bounds {
+                None => Some((t, t)),
+                Some((min, max)) => Some((f64::min(min, t), f64::max(max, t))),
+            })
+            .map(|(min, max)| (min.into(), max.into()))
+    }
+
     build_table_column_slice_getter!(
         /// Get the time column as a slice
         => time, time_slice, Time);
@@ -872,6 +975,37 @@ impl NodeTable {
     build_table_column_slice_getter!(
         /// Get the population column as a slice
         => population, population_slice_raw, crate::sys::bindings::tsk_id_t);
+
+    /// Write the table as comma-separated values.
+    ///
+    /// The first line is a header naming the columns.
+    /// Each following line is a row, starting with its id.
+    ///
+    /// * Ids are written as integers, with `-1` for null ids.
+    /// * Floating point values are written without loss of precision.
+    /// * Byte columns, such as metadata, are written as hexadecimal,
+    ///   with an empty field for missing values.
+    ///
+    /// The other table types use the same format.
+    /// See [`OwningNodeTable::read_csv`] for the reverse operation.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::LibraryError`] if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut nodes = tskit::OwningNodeTable::default();
+    /// nodes.add_row(tskit::NodeFlags::new_sample(), 0.5, -1, -1).unwrap();
+    /// let mut csv = vec![];
+    /// nodes.write_csv(&mut csv).unwrap();
+    /// assert_eq!(String::from_utf8(csv).unwrap(),
+    ///            "id,flags,time,population,individual,metadata\n0,1,0.5,-1,-1,\n");
+    /// ```
+    pub fn write_csv<W: std::io::Write>(&self, writer: &mut W) -> Result<(), TskitError> {
+        crate::table_csv::write_nodes(self, writer)
+    }
 }
 
 build_owned_table_type!(
@@ -987,6 +1121,75 @@ impl OwningNodeTable {
     ) -> Result<NodeId, TskitError> {
         add_row_with_defaults(time, defaults, self.as_mut_ptr())
     }
+
+    /// Build a table from an iterator over
+    /// `(flags, time, population, individual)` tuples.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by [`OwningNodeTable::add_row`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rows = vec![
+    ///     (tskit::NodeFlags::new_sample(), 0.0, -1, -1),
+    ///     (tskit::NodeFlags::new_sample(), 0.0, -1, -1),
+    ///     (tskit::NodeFlags::default(), 1.0, -1, -1),
+    /// ];
+    /// let nodes = tskit::OwningNodeTable::try_from_rows(rows).unwrap();
+    /// assert_eq!(nodes.num_rows(), 3);
+    /// ```
+    pub fn try_from_rows<R, F, T, P, I>(rows: R) -> Result<Self, TskitError>
+    where
+        R: IntoIterator<Item = (F, T, P, I)>,
+        F: Into<NodeFlags>,
+        T: Into<Time>,
+        P: Into<PopulationId>,
+        I: Into<IndividualId>,
+    {
+        let mut table = Self::default();
+        for (flags, time, population, individual) in rows {
+            table.add_row(flags, time, population, individual)?;
+        }
+        Ok(table)
+    }
+
+    /// Read a table written by [`NodeTable::write_csv`].
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::ValueError`] if the header, the number of fields,
+    ///   the row ids, or any value is invalid.
+    /// * [`TskitError::LibraryError`] if reading fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let csv = "id,flags,time,population,individual,metadata\n0,1,0.5,-1,-1,\n";
+    /// let nodes = tskit::OwningNodeTable::read_csv(csv.as_bytes()).unwrap();
+    /// assert_eq!(nodes.num_rows(), 1);
+    /// assert_eq!(nodes.time(0), Some(0.5.into()));
+    /// ```
+    pub fn read_csv<R: std::io::BufRead>(reader: R) -> Result<Self, TskitError> {
+        crate::table_csv::read_nodes(reader)
+    }
+}
+
+/// # Panics
+///
+/// Panics if adding a row fails.
+/// See [`OwningNodeTable::try_from_rows`] for a fallible alternative.
+impl<F, T, P, I> FromIterator<(F, T, P, I)> for OwningNodeTable
+where
+    F: Into<NodeFlags>,
+    T: Into<Time>,
+    P: Into<PopulationId>,
+    I: Into<IndividualId>,
+{
+    fn from_iter<R: IntoIterator<Item = (F, T, P, I)>>(rows: R) -> Self {
+        Self::try_from_rows(rows).unwrap()
+    }
 }
 
 #[cfg(test)]
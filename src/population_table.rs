@@ -19,6 +19,13 @@ impl PartialEq for PopulationTableRow {
     }
 }
 
+impl std::hash::Hash for PopulationTableRow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.metadata.hash(state);
+    }
+}
+
 fn make_population_table_row(table: &PopulationTable, pos: tsk_id_t) -> Option<PopulationTableRow> {
     let index = ll_bindings::tsk_size_t::try_from(pos).ok()?;
 
@@ -58,6 +65,15 @@ impl Iterator for PopulationTableIterator {
     }
 }
 
+impl<'a> IntoIterator for &'a PopulationTable {
+    type Item = PopulationTableRow;
+    type IntoIter = PopulationTableRefIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        crate::table_iterator::make_table_iterator::<&PopulationTable>(self)
+    }
+}
+
 #[derive(Debug)]
 pub struct PopulationTableRowView<'a> {
     table: &'a PopulationTable,
@@ -73,6 +89,8 @@ impl<'a> PopulationTableRowView<'a> {
             metadata: None,
         }
     }
+
+    row_view_decode_metadata!(PopulationMetadata);
 }
 
 impl<'a> PartialEq for PopulationTableRowView<'a> {
@@ -204,6 +222,17 @@ impl PopulationTable {
             _ => None,
         }
     }
+
+    /// Write the table as comma-separated values.
+    ///
+    /// See [`crate::NodeTable::write_csv`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::LibraryError`] if writing fails.
+    pub fn write_csv<W: std::io::Write>(&self, writer: &mut W) -> Result<(), TskitError> {
+        crate::table_csv::write_populations(self, writer)
+    }
 }
 
 build_owned_table_type!(
@@ -260,4 +289,64 @@ build_owned_table_type!(
 impl OwningPopulationTable {
     population_table_add_row!(=> add_row, self, self.as_mut_ptr());
     population_table_add_row_with_metadata!(=> add_row_with_metadata, self, self.as_mut_ptr());
+
+    /// Build a table with one row for each item of an
+    /// iterator over metadata.
+    ///
+    /// Population rows have no columns other than metadata.
+    /// Use [`OwningPopulationTable::add_row`] to add rows
+    /// without metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by
+    /// [`OwningPopulationTable::add_row_with_metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "derive")] {
+    /// #[derive(serde::Serialize, serde::Deserialize, tskit::metadata::PopulationMetadata)]
+    /// #[serializer("serde_json")]
+    /// struct Population {
+    ///     name: String,
+    /// }
+    /// let rows = ["YRI", "CEU"].map(|name| Population { name: name.to_string() });
+    /// let populations = tskit::OwningPopulationTable::try_from_rows(rows).unwrap();
+    /// assert_eq!(populations.num_rows(), 2);
+    /// # }
+    /// ```
+    pub fn try_from_rows<I, M>(rows: I) -> Result<Self, TskitError>
+    where
+        I: IntoIterator<Item = M>,
+        M: metadata::PopulationMetadata,
+    {
+        let mut table = Self::default();
+        for metadata in rows {
+            table.add_row_with_metadata(&metadata)?;
+        }
+        Ok(table)
+    }
+
+    /// Read a table written by [`PopulationTable::write_csv`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::OwningNodeTable::read_csv`].
+    pub fn read_csv<R: std::io::BufRead>(reader: R) -> Result<Self, TskitError> {
+        crate::table_csv::read_populations(reader)
+    }
+}
+
+/// # Panics
+///
+/// Panics if adding a row fails.
+/// See [`OwningPopulationTable::try_from_rows`] for a fallible alternative.
+impl<M> FromIterator<M> for OwningPopulationTable
+where
+    M: metadata::PopulationMetadata,
+{
+    fn from_iter<I: IntoIterator<Item = M>>(rows: I) -> Self {
+        Self::try_from_rows(rows).unwrap()
+    }
 }
@@ -34,6 +34,14 @@ impl PartialEq for ProvenanceTableRow {
     }
 }
 
+impl std::hash::Hash for ProvenanceTableRow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.timestamp.hash(state);
+        self.record.hash(state);
+    }
+}
+
 impl std::fmt::Display for ProvenanceTableRow {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
@@ -75,6 +83,15 @@ impl Iterator for ProvenanceTableIterator {
     }
 }
 
+impl<'a> IntoIterator for &'a ProvenanceTable {
+    type Item = ProvenanceTableRow;
+    type IntoIter = ProvenanceTableRefIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        crate::table_iterator::make_table_iterator::<&ProvenanceTable>(self)
+    }
+}
+
 #[derive(Debug)]
 pub struct ProvenanceTableRowView<'a> {
     table: &'a ProvenanceTable,
@@ -269,6 +286,20 @@ impl ProvenanceTable {
     pub fn lending_iter(&self) -> ProvenanceTableRowView {
         ProvenanceTableRowView::new(self)
     }
+
+    /// Write the table as comma-separated values.
+    ///
+    /// See [`crate::NodeTable::write_csv`] for the format.
+    /// The columns are the id, timestamp, and record.
+    /// Because they are free text, the timestamp and record
+    /// are written as hexadecimal.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::LibraryError`](crate::TskitError::LibraryError) if writing fails.
+    pub fn write_csv<W: std::io::Write>(&self, writer: &mut W) -> Result<(), crate::TskitError> {
+        crate::table_csv::write_provenances(self, writer)
+    }
 }
 
 build_owned_table_type!(
@@ -295,6 +326,17 @@ build_owned_table_type!(
 
 impl OwningProvenanceTable {
     provenance_table_add_row!(=> add_row, self, self.as_mut_ptr());
+
+    /// Read a table written by [`ProvenanceTable::write_csv`].
+    ///
+    /// # Errors
+    ///
+    /// * See [`crate::OwningNodeTable::read_csv`].
+    /// * [`TskitError::ValueError`](crate::TskitError::ValueError) if a
+    ///   timestamp or record is not UTF-8 text.
+    pub fn read_csv<R: std::io::BufRead>(reader: R) -> Result<Self, crate::TskitError> {
+        crate::table_csv::read_provenances(reader)
+    }
 }
 
 #[cfg(test)]
@@ -16,15 +16,38 @@ pub struct SiteTableRow {
     pub metadata: Option<Vec<u8>>,
 }
 
+impl SiteTableRow {
+    /// Get the ancestral state as a [`str`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` if there is an ancestral state and it is valid UTF-8.
+    /// * `None` otherwise.
+    pub fn ancestral_state_str(&self) -> Option<&str> {
+        std::str::from_utf8(self.ancestral_state.as_ref()?).ok()
+    }
+}
+
 impl PartialEq for SiteTableRow {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
-            && crate::util::partial_cmp_equal(&self.position, &other.position)
+            && crate::util::hash_eq_f64(self.position, other.position)
             && self.ancestral_state == other.ancestral_state
             && self.metadata == other.metadata
     }
 }
 
+impl Eq for SiteTableRow {}
+
+impl std::hash::Hash for SiteTableRow {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        crate::util::hash_f64(self.position, state);
+        self.ancestral_state.hash(state);
+        self.metadata.hash(state);
+    }
+}
+
 fn make_site_table_row(table: &SiteTable, pos: tsk_id_t) -> Option<SiteTableRow> {
     let ancestral_state = table.ancestral_state(pos).map(|s| s.to_vec());
     Some(SiteTableRow {
@@ -58,6 +81,15 @@ impl Iterator for SiteTableIterator {
     }
 }
 
+impl<'a> IntoIterator for &'a SiteTable {
+    type Item = SiteTableRow;
+    type IntoIter = SiteTableRefIterator<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        crate::table_iterator::make_table_iterator::<&SiteTable>(self)
+    }
+}
+
 #[derive(Debug)]
 pub struct SiteTableRowView<'a> {
     table: &'a SiteTable,
@@ -77,6 +109,8 @@ impl<'a> SiteTableRowView<'a> {
             metadata: None,
         }
     }
+
+    row_view_decode_metadata!(SiteMetadata);
 }
 
 impl<'a> PartialEq for SiteTableRowView<'a> {
@@ -180,6 +214,28 @@ impl SiteTable {
         )
     }
 
+    /// Get the ``ancestral_state`` value from row ``row`` of the table
+    /// as a [`str`].
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&str)` if `row` is valid, has an ancestral state,
+    ///   and the ancestral state is valid UTF-8.
+    /// * `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// let site = tables.add_site(1., Some(b"G")).unwrap();
+    /// assert_eq!(tables.sites().ancestral_state_str(site), Some("G"));
+    /// let row = tables.sites().row(site).unwrap();
+    /// assert_eq!(row.ancestral_state_str(), Some("G"));
+    /// ```
+    pub fn ancestral_state_str<S: Into<SiteId>>(&self, row: S) -> Option<&str> {
+        std::str::from_utf8(self.ancestral_state(row)?).ok()
+    }
+
     /// Retrieve decoded metadata for a `row`.
     ///
     /// # Returns
@@ -250,12 +306,92 @@ impl SiteTable {
         Some(view)
     }
 
+    /// Return an iterator over the ancestral states of all rows.
+    ///
+    /// Each item is `None` if the site has no ancestral state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// tables.add_site(1.0, Some(b"A")).unwrap();
+    /// tables.add_site(2.0, None).unwrap();
+    /// let states = tables.sites().ancestral_states().collect::<Vec<_>>();
+    /// assert_eq!(states, [Some(b"A".as_slice()), None]);
+    /// ```
+    pub fn ancestral_states(&self) -> impl Iterator<Item = Option<&[u8]>> + '_ {
+        (0..self.num_rows().as_usize()).map(move |row| self.ancestral_state(row as tsk_id_t))
+    }
+
     build_table_column_slice_getter!(
         /// Get the position column as a slice
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+        /// for position in [1.0, 2.5, 7.0] {
+        ///     tables.add_site(position, None).unwrap();
+        /// }
+        /// let positions = tables.sites().position_slice();
+        /// assert_eq!(positions.len(), tables.sites().num_rows().as_usize());
+        /// assert_eq!(positions[1], 2.5);
+        /// ```
         => position, position_slice, Position);
     build_table_column_slice_getter!(
         /// Get the position column as a slice
         => position, position_slice_raw, f64);
+    build_table_column_slice_mut_getter!(
+    /// Get the position column as a mutable slice
+    ///
+    /// # Examples
+    ///
+    /// For a [`crate::TableCollection`], accessing the table creates a temporary
+    /// that will be dropped, causing this code to not compile:
+    ///
+    /// ```compile_fail
+    /// # let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// # tables.add_site(1.0, None).unwrap();
+    /// let positions = tables.sites().position_slice_mut();
+    /// println!("{}", positions.len()); // ERROR: the temporary site table is dropped by now
+    /// ```
+    ///
+    /// Treating the returned slice as an iterable succeeds:
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// # tables.add_site(1.0, None).unwrap();
+    /// # tables.add_site(2.0, None).unwrap();
+    /// for position in tables.sites_mut().position_slice_mut() {
+    ///     *position = (f64::from(*position) * 2.0).into(); // rescale each position
+    /// }
+    /// assert_eq!(tables.sites().position_slice(), [2.0, 4.0]);
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// Changing positions may leave the table unsorted.
+    ///
+    /// # Panics
+    ///
+    /// Internally, we rely on a conversion of u64 to usize.
+    /// This conversion is fallible on some platforms.
+    /// If the conversion fails, this function will panic.
+        => position, position_slice_mut, Position);
+    build_table_column_slice_mut_getter!(
+        /// Get the position column as a mutable slice
+        => position, position_slice_raw_mut, f64);
+
+    /// Write the table as comma-separated values.
+    ///
+    /// See [`crate::NodeTable::write_csv`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::LibraryError`] if writing fails.
+    pub fn write_csv<W: std::io::Write>(&self, writer: &mut W) -> Result<(), TskitError> {
+        crate::table_csv::write_sites(self, writer)
+    }
 }
 
 build_owned_table_type!(
@@ -312,4 +448,52 @@ build_owned_table_type!(
 impl OwningSiteTable {
     site_table_add_row!(=> add_row, self, self.as_mut_ptr());
     site_table_add_row_with_metadata!(=> add_row_with_metadata, self, self.as_mut_ptr());
+
+    /// Build a table from an iterator over
+    /// `(position, ancestral_state)` tuples.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered by [`OwningSiteTable::add_row`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rows = vec![(1., Some(b"A".as_slice())), (2., None)];
+    /// let sites = tskit::OwningSiteTable::try_from_rows(rows).unwrap();
+    /// assert_eq!(sites.num_rows(), 2);
+    /// ```
+    pub fn try_from_rows<'a, I, P>(rows: I) -> Result<Self, TskitError>
+    where
+        I: IntoIterator<Item = (P, Option<&'a [u8]>)>,
+        P: Into<Position>,
+    {
+        let mut table = Self::default();
+        for (position, ancestral_state) in rows {
+            table.add_row(position, ancestral_state)?;
+        }
+        Ok(table)
+    }
+
+    /// Read a table written by [`SiteTable::write_csv`].
+    ///
+    /// # Errors
+    ///
+    /// See [`crate::OwningNodeTable::read_csv`].
+    pub fn read_csv<R: std::io::BufRead>(reader: R) -> Result<Self, TskitError> {
+        crate::table_csv::read_sites(reader)
+    }
+}
+
+/// # Panics
+///
+/// Panics if adding a row fails.
+/// See [`OwningSiteTable::try_from_rows`] for a fallible alternative.
+impl<'a, P> FromIterator<(P, Option<&'a [u8]>)> for OwningSiteTable
+where
+    P: Into<Position>,
+{
+    fn from_iter<I: IntoIterator<Item = (P, Option<&'a [u8]>)>>(rows: I) -> Self {
+        Self::try_from_rows(rows).unwrap()
+    }
 }
@@ -26,6 +26,20 @@ macro_rules! impl_flags {
             pub fn is_valid(&self) -> bool {
                 Self::from_bits(self.bits()).is_some()
             }
+
+            /// Create flags from `bits`, returning an error
+            /// if any bits are set that do not correspond to allowed flags.
+            ///
+            /// # Errors
+            ///
+            /// [`TskitError::ValueError`](crate::TskitError::ValueError)
+            /// if `bits` has unknown bits set.
+            pub fn from_bits_checked(bits: RawFlags) -> Result<Self, crate::TskitError> {
+                Self::from_bits(bits).ok_or_else(|| crate::TskitError::ValueError {
+                    got: format!("unknown flag bits {:#x}", bits & !Self::all().bits()),
+                    expected: format!("flags within {:#x}", Self::all().bits()),
+                })
+            }
         }
     };
 }
@@ -283,6 +297,20 @@ bitflags! {
     /// assert!(f.contains(tskit::TableEqualityOptions::IGNORE_PROVENANCE));
     /// assert!(f.contains(tskit::TableEqualityOptions::IGNORE_METADATA));
     /// ```
+    ///
+    /// ### Comparing tables
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// tables.add_node(0, 0.0, -1, -1).unwrap();
+    /// let mut other = tables.deepcopy().unwrap();
+    /// other.set_time_units("generations").unwrap();
+    /// let options = tskit::TableEqualityOptions::default()
+    ///     .ignore_metadata()
+    ///     .ignore_provenance();
+    /// assert!(tables.equals(&tables.deepcopy().unwrap(), options));
+    /// assert!(!tables.equals(&other, options));
+    /// ```
     #[derive(Default)]
     #[repr(transparent)]
     pub struct TableEqualityOptions : RawFlags {
@@ -303,7 +331,7 @@ impl TableEqualityOptions {
         /// Set [`IGNORE_TS_METADATA`](crate::TableEqualityOptions::IGNORE_TS_METADATA)
         => ignore_ts_metadata, IGNORE_TS_METADATA);
     flag_builder_api!(
-        /// Set [:IGNORE_PROVENANCE`](crate::TableEqualityOptions::IGNORE_PROVENANCE)
+        /// Set [`IGNORE_PROVENANCE`](crate::TableEqualityOptions::IGNORE_PROVENANCE)
         => ignore_provenance, IGNORE_PROVENANCE);
     flag_builder_api!(
         /// Set [`IGNORE_TIMESTAMPS`](crate::TableEqualityOptions::IGNORE_TIMESTAMPS)
@@ -441,6 +469,53 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Modify behavior of [`crate::TableCollection::new_from_file_with_options`].
+    ///
+    /// # Examples
+    ///
+    /// ## Default (empty) flags
+    ///
+    /// ```
+    /// let f = tskit::TableLoadOptions::default();
+    /// assert_eq!(f, tskit::TableLoadOptions::NONE);
+    /// ```
+    ///
+    /// ## Builder API
+    ///
+    /// These methods may be chained.
+    ///
+    /// ```
+    /// let f = tskit::TableLoadOptions::default().skip_tables();
+    /// assert_eq!(f, tskit::TableLoadOptions::SKIP_TABLES);
+    /// ```
+    ///
+    /// ```
+    /// let f = tskit::TableLoadOptions::default().skip_reference_sequence();
+    /// assert_eq!(f, tskit::TableLoadOptions::SKIP_REFERENCE_SEQUENCE);
+    /// ```
+    #[derive(Default)]
+    #[repr(transparent)]
+    pub struct TableLoadOptions : RawFlags {
+        /// Default behavior
+        const NONE = 0;
+        /// Only load the non-table information from the file.
+        /// All tables will be empty.
+        const SKIP_TABLES = ll_bindings::TSK_LOAD_SKIP_TABLES;
+        /// Do not load the reference sequence.
+        const SKIP_REFERENCE_SEQUENCE = ll_bindings::TSK_LOAD_SKIP_REFERENCE_SEQUENCE;
+    }
+}
+
+impl TableLoadOptions {
+    flag_builder_api!(
+        /// Set [`SKIP_TABLES`](crate::TableLoadOptions::SKIP_TABLES)
+        => skip_tables, SKIP_TABLES);
+    flag_builder_api!(
+        /// Set [`SKIP_REFERENCE_SEQUENCE`](crate::TableLoadOptions::SKIP_REFERENCE_SEQUENCE)
+        => skip_reference_sequence, SKIP_REFERENCE_SEQUENCE);
+}
+
 bitflags! {
     /// Modify behavior of [`crate::TableCollection::tree_sequence`]
     /// and [`crate::TreeSequence::new`].
@@ -616,6 +691,23 @@ bitflags! {
     /// let f = tskit::NodeFlags::default().mark_sample();
     /// assert_eq!(f, tskit::NodeFlags::IS_SAMPLE);
     /// ```
+    ///
+    /// ## Ancestral recombination graphs
+    ///
+    /// The C library only defines `IS_SAMPLE`.
+    /// Ancestral recombination graphs (ARGs) mark nodes with the
+    /// event that created them, using bits outside of
+    /// [`NodeFlags::RESERVED_BITS`].
+    /// The values of these bits follow the conventions of
+    /// [`msprime`](https://tskit.dev/msprime/docs/stable/ancestry.html#ancestry-full-arg).
+    ///
+    /// ```
+    /// let f = tskit::NodeFlags::new_sample().mark_recombination();
+    /// assert!(f.is_sample());
+    /// assert!(f.is_recombination_node());
+    /// assert!(!f.is_common_ancestor_node());
+    /// assert_eq!(f.user_bits(), tskit::NodeFlags::IS_RE_EVENT);
+    /// ```
     #[derive(Default)]
     #[repr(transparent)]
     pub struct NodeFlags : RawFlags {
@@ -626,6 +718,26 @@ bitflags! {
     }
 }
 
+macro_rules! arg_node_flag_api {
+    ($(#[$attr:meta])* $flag: ident = $shift: expr => $is: ident, $mark: ident) => {
+        $(#[$attr])*
+        pub const $flag: RawFlags = 1 << $shift;
+
+        /// Returns `true` if flags contains
+        #[doc = concat!("[`", stringify!($flag), "`](crate::NodeFlags::", stringify!($flag), "),")]
+        /// and `false` otherwise.
+        pub fn $is(&self) -> bool {
+            self.bits() & Self::$flag != 0
+        }
+
+        /// Set
+        #[doc = concat!("[`", stringify!($flag), "`](crate::NodeFlags::", stringify!($flag), ").")]
+        pub fn $mark(self) -> Self {
+            self.with_user_bits(Self::$flag)
+        }
+    };
+}
+
 impl NodeFlags {
     /// Create a new flags instance with `IS_SAMPLE` set.
     pub fn new_sample() -> Self {
@@ -648,17 +760,132 @@ impl NodeFlags {
         true
     }
 
+    /// Create flags from `bits`, returning an error if any of
+    /// [`NodeFlags::RESERVED_BITS`] are set that do not correspond
+    /// to flags defined by `tskit`.
+    ///
+    /// User-defined bits are accepted as-is.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`](crate::TskitError::ValueError)
+    /// if `bits` has unknown reserved bits set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = tskit::NodeFlags::from_bits_checked(1 | (1 << 17)).unwrap();
+    /// assert!(f.is_sample());
+    /// assert!(f.is_recombination_node());
+    /// assert!(tskit::NodeFlags::from_bits_checked(1 << 3).is_err());
+    /// ```
+    pub fn from_bits_checked(bits: RawFlags) -> Result<Self, crate::TskitError> {
+        let unknown = bits & Self::RESERVED_BITS & !Self::all().bits();
+        match unknown {
+            0 => Ok(bits.into()),
+            _ => Err(crate::TskitError::ValueError {
+                got: format!("unknown reserved flag bits {:#x}", unknown),
+                expected: format!(
+                    "reserved flags within {:#x} or user-defined flags",
+                    Self::all().bits()
+                ),
+            }),
+        }
+    }
+
     /// Returns `true` if flags contains `IS_SAMPLE`,
     /// and `false` otherwise.
     pub fn is_sample(&self) -> bool {
         self.contains(NodeFlags::IS_SAMPLE)
     }
+
+    /// Bits reserved for use by `tskit`.
+    ///
+    /// As per the C API docs, the lower 16 bits of node
+    /// flags are reserved. Users may define flags in the
+    /// remaining upper bits.
+    pub const RESERVED_BITS: RawFlags = 0xFFFF;
+
+    /// Set user-defined flag bits.
+    ///
+    /// Any of `bits` that overlap with [`NodeFlags::RESERVED_BITS`]
+    /// are ignored, meaning that this function cannot be used
+    /// to set or clear [`IS_SAMPLE`](crate::NodeFlags::IS_SAMPLE).
+    /// Existing flags are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let user_bit = 1 << 16;
+    /// let f = tskit::NodeFlags::new_sample().with_user_bits(user_bit);
+    /// assert!(f.is_sample());
+    /// assert_eq!(f.user_bits(), user_bit);
+    ///
+    /// // Reserved bits are not set
+    /// let f = tskit::NodeFlags::default().with_user_bits(tskit::NodeFlags::IS_SAMPLE.bits());
+    /// assert!(!f.is_sample());
+    /// assert_eq!(f.user_bits(), 0);
+    /// ```
+    pub fn with_user_bits(self, bits: RawFlags) -> Self {
+        // Safety: node flags can contain user-defined values
+        // outside of the reserved bits.
+        unsafe { Self::from_bits_unchecked(self.bits() | (bits & !Self::RESERVED_BITS)) }
+    }
+
+    /// Get the user-defined flag bits.
+    ///
+    /// The returned value has all of [`NodeFlags::RESERVED_BITS`] unset.
+    pub fn user_bits(&self) -> RawFlags {
+        self.bits() & !Self::RESERVED_BITS
+    }
+
+    arg_node_flag_api!(
+        /// The node is a recombination event.
+        IS_RE_EVENT = 17 => is_recombination_node, mark_recombination);
+    arg_node_flag_api!(
+        /// The node is a common ancestor event.
+        IS_CA_EVENT = 18 => is_common_ancestor_node, mark_common_ancestor);
+    arg_node_flag_api!(
+        /// The node is a migration event.
+        IS_MIG_EVENT = 19 => is_migration_node, mark_migration);
+    arg_node_flag_api!(
+        /// The node is a census event.
+        IS_CEN_EVENT = 20 => is_census_node, mark_census);
+    arg_node_flag_api!(
+        /// The node is a gene conversion event.
+        IS_GC_EVENT = 21 => is_gene_conversion_node, mark_gene_conversion);
 }
 
 bitflags! {
     #[derive(Default)]
     #[repr(transparent)]
     /// Individual flags
+    ///
+    /// The C library does not define any individual flags.
+    /// As for [`NodeFlags`], the lower 16 bits are reserved
+    /// for use by `tskit` and user-defined flags should be
+    /// set in the remaining upper bits.
+    ///
+    /// # Examples
+    ///
+    /// ## Default (empty) flags
+    ///
+    /// ```
+    /// let f = tskit::IndividualFlags::new();
+    /// assert_eq!(f, tskit::IndividualFlags::NONE);
+    /// ```
+    ///
+    /// ## User-defined flags
+    ///
+    /// ```
+    /// const ALIVE: tskit::RawFlags = 1 << 16;
+    /// let flags = tskit::IndividualFlags::new().with_user_bits(ALIVE);
+    /// assert_eq!(flags.user_bits(), ALIVE);
+    ///
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// let individual = tables.add_individual(flags, None, None).unwrap();
+    /// assert_eq!(tables.individuals().flags(individual), Some(flags));
+    /// ```
     pub struct IndividualFlags : RawFlags {
         /// Default (empty)
         const NONE = 0;
@@ -666,12 +893,69 @@ bitflags! {
 }
 
 impl IndividualFlags {
+    /// Create a new flags instance with no bits set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
     /// We do not enforce valid flags in the library.
     /// This function will return `true` if any bits
     /// are set that do not correspond to allowed flags.
     pub fn is_valid(&self) -> bool {
         true
     }
+
+    /// Create flags from `bits`, returning an error if any of
+    /// [`IndividualFlags::RESERVED_BITS`] are set.
+    ///
+    /// User-defined bits are accepted as-is.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`](crate::TskitError::ValueError)
+    /// if `bits` has reserved bits set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let f = tskit::IndividualFlags::from_bits_checked(1 << 16).unwrap();
+    /// assert_eq!(f.user_bits(), 1 << 16);
+    /// assert!(tskit::IndividualFlags::from_bits_checked(1).is_err());
+    /// ```
+    pub fn from_bits_checked(bits: RawFlags) -> Result<Self, crate::TskitError> {
+        let unknown = bits & Self::RESERVED_BITS & !Self::all().bits();
+        match unknown {
+            0 => Ok(bits.into()),
+            _ => Err(crate::TskitError::ValueError {
+                got: format!("unknown reserved flag bits {:#x}", unknown),
+                expected: "user-defined flags".to_string(),
+            }),
+        }
+    }
+
+    /// Bits reserved for use by `tskit`.
+    ///
+    /// These are the lower 16 bits, matching
+    /// [`NodeFlags::RESERVED_BITS`].
+    pub const RESERVED_BITS: RawFlags = 0xFFFF;
+
+    /// Set user-defined flag bits.
+    ///
+    /// Any of `bits` that overlap with [`IndividualFlags::RESERVED_BITS`]
+    /// are ignored.
+    /// Existing flags are preserved.
+    pub fn with_user_bits(self, bits: RawFlags) -> Self {
+        // Safety: individual flags can contain user-defined values
+        // outside of the reserved bits.
+        unsafe { Self::from_bits_unchecked(self.bits() | (bits & !Self::RESERVED_BITS)) }
+    }
+
+    /// Get the user-defined flag bits.
+    ///
+    /// The returned value has all of [`IndividualFlags::RESERVED_BITS`] unset.
+    pub fn user_bits(&self) -> RawFlags {
+        self.bits() & !Self::RESERVED_BITS
+    }
 }
 
 impl_flags!(SimplificationOptions);
@@ -683,6 +967,7 @@ impl_flags!(TreeFlags);
 impl_flags!(IndividualTableSortOptions);
 impl_flags!(TableIntegrityCheckFlags);
 impl_flags!(TableOutputOptions);
+impl_flags!(TableLoadOptions);
 
 impl_from_for_flag_types!(SimplificationOptions);
 impl_from_for_flag_types!(TableClearOptions);
@@ -693,6 +978,7 @@ impl_from_for_flag_types!(TreeFlags);
 impl_from_for_flag_types!(IndividualTableSortOptions);
 impl_from_for_flag_types!(TableIntegrityCheckFlags);
 impl_from_for_flag_types!(TableOutputOptions);
+impl_from_for_flag_types!(TableLoadOptions);
 
 impl From<RawFlags> for NodeFlags {
     fn from(flags: RawFlags) -> Self {
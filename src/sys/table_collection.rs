@@ -32,6 +32,15 @@ impl TableCollection {
         Self(tsk)
     }
 
+    // # Safety
+    //
+    // `tables` must be non-null, initialized, and allocated
+    // with `libc::malloc`.
+    // The returned value takes ownership of the pointer.
+    pub unsafe fn from_raw(tables: *mut tsk_table_collection_t) -> Self {
+        Self(unsafe { TskBox::from_raw(tables) })
+    }
+
     pub fn copy(&self) -> (i32, TableCollection) {
         // SAFETY: the C API requires that the destiniation of a copy be uninitalized.
         // Copying into it will initialize the object.
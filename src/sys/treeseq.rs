@@ -19,6 +19,17 @@ impl LLTreeSeq {
         }
     }
 
+    // Take ownership of the table collection.
+    //
+    // The tables are detached before the tree sequence
+    // is freed, so that they are not freed with it.
+    pub fn into_tables(self) -> *mut bindings::tsk_table_collection_t {
+        let mut treeseq = self;
+        let tables = treeseq.0.tables;
+        treeseq.0.tables = std::ptr::null_mut();
+        tables
+    }
+
     pub fn as_ref(&self) -> &bindings::tsk_treeseq_t {
         &self.0
     }
@@ -45,6 +45,16 @@ where
         Self { tsk, owning: true }
     }
 
+    // # Safety
+    //
+    // `tsk` must be non-null, initialized, and allocated
+    // with `libc::malloc`.
+    // The returned value takes ownership of the pointer.
+    pub unsafe fn from_raw(tsk: *mut T) -> Self {
+        let tsk = NonNull::new(tsk).unwrap();
+        Self { tsk, owning: true }
+    }
+
     /// # Safety
     ///
     /// This function clones the NonNull of `owner` and will
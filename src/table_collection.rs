@@ -11,6 +11,7 @@ use crate::SimplificationOptions;
 use crate::TableClearOptions;
 use crate::TableEqualityOptions;
 use crate::TableIntegrityCheckFlags;
+use crate::TableLoadOptions;
 use crate::TableOutputOptions;
 use crate::TableSortOptions;
 use crate::TreeSequenceFlags;
@@ -89,6 +90,83 @@ impl TableCollection {
         })
     }
 
+    /// Create a new table collection whose sequence length
+    /// will be inferred from its contents.
+    ///
+    /// The sequence length is initially `0.0`.
+    /// Call [`TableCollection::finalize_length`] once all
+    /// edges, sites, and migrations have been added.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library is unable
+    /// to allocate the tables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new_inferring_length().unwrap();
+    /// assert_eq!(tables.sequence_length(), 0.0);
+    /// tables.add_edge(0., 80., 0, 1).unwrap();
+    /// tables.finalize_length().unwrap();
+    /// assert_eq!(tables.sequence_length(), 80.0);
+    /// ```
+    pub fn new_inferring_length() -> Result<Self, TskitError> {
+        let mut inner = LLTableCollection::new(0.0)?;
+        let views = crate::table_views::TableViews::new_from_ll_table_collection(&mut inner)?;
+        Ok(Self {
+            inner,
+            idmap: vec![],
+            views,
+        })
+    }
+
+    /// Set the sequence length to the largest coordinate
+    /// found in the tables.
+    ///
+    /// The coordinates considered are the right end of each
+    /// edge and migration.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::ValueError`] if the tables contain no
+    ///   edges or migrations.
+    /// * [`TskitError::ValueError`] if a site position is not less
+    ///   than the new sequence length.
+    ///   The sequence length is unchanged.
+    pub fn finalize_length(&mut self) -> Result<(), TskitError> {
+        let max_coordinate = self
+            .edges()
+            .right_slice_raw()
+            .iter()
+            .chain(self.migrations().right_slice_raw().iter())
+            .copied()
+            .fold(None, |a: Option<f64>, b| Some(a.map_or(b, |a| a.max(b))));
+        let length = match max_coordinate {
+            Some(length) if length > 0.0 => length,
+            _ => {
+                return Err(TskitError::ValueError {
+                    got: "no coordinates > 0.0".to_string(),
+                    expected: "at least one edge or migration".to_string(),
+                })
+            }
+        };
+        if let Some(&position) = self
+            .sites()
+            .position_slice_raw()
+            .iter()
+            .find(|&&p| p >= length)
+        {
+            return Err(TskitError::ValueError {
+                got: format!("site position {} >= sequence length {}", position, length),
+                expected: "site positions less than the sequence length".to_string(),
+            });
+        }
+        // SAFETY: self pointer is not null
+        unsafe { (*self.as_mut_ptr()).sequence_length = length };
+        Ok(())
+    }
+
     pub(crate) fn new_from_ll(lltables: LLTableCollection) -> Result<Self, TskitError> {
         let mut inner = lltables;
         let views = crate::table_views::TableViews::new_from_ll_table_collection(&mut inner)?;
@@ -137,6 +215,41 @@ impl TableCollection {
     /// This function allocates a `CString` to pass the file name to the C API.
     /// A panic will occur if the system runs out of memory.
     pub fn new_from_file(filename: impl AsRef<str>) -> Result<Self, TskitError> {
+        Self::new_from_file_with_options(filename, TableLoadOptions::default())
+    }
+
+    /// Load a table collection from a file, passing options to the
+    /// C library.
+    ///
+    /// # Parameters
+    ///
+    /// * `filename`: the name of the file
+    /// * `options`: a [`TableLoadOptions`] bit field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// # tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.dump("trees_load_options.file", tskit::TableOutputOptions::default()).unwrap();
+    /// let tables = tskit::TableCollection::new_from_file_with_options(
+    ///     "trees_load_options.file",
+    ///     tskit::TableLoadOptions::default().skip_tables(),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(tables.sequence_length(), 100.);
+    /// assert_eq!(tables.nodes().num_rows(), 0);
+    /// # std::fs::remove_file("trees_load_options.file").unwrap();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function allocates a `CString` to pass the file name to the C API.
+    /// A panic will occur if the system runs out of memory.
+    pub fn new_from_file_with_options<O: Into<TableLoadOptions>>(
+        filename: impl AsRef<str>,
+        options: O,
+    ) -> Result<Self, TskitError> {
         // Arbitrary sequence_length.
         let mut tables = match TableCollection::new(1.0) {
             Ok(t) => t,
@@ -150,13 +263,54 @@ impl TableCollection {
             ll_bindings::tsk_table_collection_load(
                 tables.as_mut_ptr(),
                 c_str.as_ptr(),
-                ll_bindings::TSK_NO_INIT,
+                options.into().bits() | ll_bindings::TSK_NO_INIT,
             )
         };
 
         handle_tsk_return_value!(rv, tables)
     }
 
+    /// Create a table collection from a single tree in Newick format.
+    ///
+    /// The tree spans the entire sequence.
+    /// Tips are marked as samples and are numbered first, from left
+    /// to right, followed by the internal nodes.
+    /// Node times are obtained from branch lengths, with the tips
+    /// furthest from the root at time zero.
+    /// Node labels are ignored.
+    ///
+    /// The returned tables are sorted and indexed.
+    ///
+    /// # Parameters
+    ///
+    /// * `newick`: the tree, which must be terminated by `;`.
+    /// * `sequence_length`: the sequence length.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::ValueError`] if `newick` is malformed or if
+    ///   any non-root node lacks a branch length greater than zero.
+    /// * [`TskitError`] if `sequence_length` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("((A:1,B:1):2,C:3);", 100.).unwrap();
+    /// assert_eq!(tables.nodes().num_rows(), 5);
+    /// assert_eq!(tables.edges().num_rows(), 4);
+    /// // The root
+    /// assert_eq!(tables.nodes().time(4).unwrap(), 3.0);
+    /// assert!(tables.nodes().flags(0).unwrap().is_sample());
+    ///
+    /// assert!(tskit::TableCollection::from_newick("((A:1,B:1);", 100.).is_err());
+    /// ```
+    pub fn from_newick<P: Into<Position>>(
+        newick: &str,
+        sequence_length: P,
+    ) -> Result<Self, TskitError> {
+        crate::newick::tables_from_newick(newick, sequence_length.into())
+    }
+
     /// Length of the sequence/"genome".
     /// # Examples
     ///
@@ -168,6 +322,50 @@ impl TableCollection {
         self.inner.sequence_length().into()
     }
 
+    /// Get the time units.
+    ///
+    /// # Returns
+    ///
+    /// * `None` if the time units are empty or are not valid UTF-8.
+    /// * `Some(String)` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// // The C library default
+    /// assert_eq!(tables.time_units(), Some("unknown".to_string()));
+    /// tables.set_time_units("generations").unwrap();
+    /// assert_eq!(tables.time_units(), Some("generations".to_string()));
+    /// ```
+    pub fn time_units(&self) -> Option<String> {
+        // SAFETY: self pointer is not null
+        let tables = unsafe { &*self.as_ptr() };
+        if tables.time_units_length == 0 {
+            return None;
+        }
+        let bytes: &[u8] = crate::sys::generate_slice(tables.time_units, tables.time_units_length);
+        std::str::from_utf8(bytes).ok().map(|s| s.to_string())
+    }
+
+    /// Set the time units.
+    ///
+    /// Any existing value is replaced.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C function returns an error.
+    pub fn set_time_units(&mut self, units: &str) -> TskReturnValue {
+        let rv = unsafe {
+            ll_bindings::tsk_table_collection_set_time_units(
+                self.as_mut_ptr(),
+                units.as_ptr() as *const std::os::raw::c_char,
+                units.len() as ll_bindings::tsk_size_t,
+            )
+        };
+        handle_tsk_return_value!(rv)
+    }
+
     edge_table_add_row!(
     /// Add a row to the edge table
     ///
@@ -236,6 +434,76 @@ impl TableCollection {
     /// ```
     => add_edge_with_metadata, self, &mut(*self.as_mut_ptr()).edges);
 
+    /// Add a row to the edge table, checking the row first.
+    ///
+    /// Unlike [`TableCollection::add_edge`], which allows invalid data,
+    /// this function returns an error instead of adding the row unless:
+    ///
+    /// * `0 <= left < right <= sequence_length`, and
+    /// * `parent` and `child` are not null.
+    ///
+    /// The nodes are not required to exist yet.
+    /// See [`ValidatingTableCollection`](crate::ValidatingTableCollection)
+    /// for checking all rows of all tables.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::RangeError`] if the coordinates are invalid.
+    /// * [`TskitError::ValueError`] if either node is null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// assert_eq!(tables.try_add_edge(0., 53., 1, 11).unwrap(), 0);
+    /// assert!(tables.try_add_edge(0., 153., 1, 11).is_err());
+    /// assert!(tables.try_add_edge(0., 53., tskit::NodeId::NULL, 11).is_err());
+    /// assert_eq!(tables.edges().num_rows(), 1);
+    /// ```
+    pub fn try_add_edge<L, R, P, C>(
+        &mut self,
+        left: L,
+        right: R,
+        parent: P,
+        child: C,
+    ) -> Result<EdgeId, TskitError>
+    where
+        L: Into<Position>,
+        R: Into<Position>,
+        P: Into<NodeId>,
+        C: Into<NodeId>,
+    {
+        let (left, right, parent, child) = (left.into(), right.into(), parent.into(), child.into());
+        self.check_edge(left, right, parent, child)?;
+        self.add_edge(left, right, parent, child)
+    }
+
+    // The checks done by try_add_edge.
+    pub(crate) fn check_edge(
+        &self,
+        left: Position,
+        right: Position,
+        parent: NodeId,
+        child: NodeId,
+    ) -> Result<(), TskitError> {
+        let sequence_length = self.sequence_length();
+        if !(left >= 0.0 && left < right && right <= sequence_length) {
+            return Err(TskitError::RangeError(format!(
+                "edge coordinates left = {}, right = {} do not satisfy 0 <= left < right <= {}",
+                left, right, sequence_length
+            )));
+        }
+        for (node, column) in [(parent, "parent"), (child, "child")] {
+            if node < 0 {
+                return Err(TskitError::ValueError {
+                    got: format!("{} node {}", column, node),
+                    expected: format!("a non-null {} node", column),
+                });
+            }
+        }
+        Ok(())
+    }
+
     individual_table_add_row!(
     /// Add a row to the individual table
     ///
@@ -319,6 +587,21 @@ impl TableCollection {
     /// ```
     => add_migration, self, &mut (*self.as_mut_ptr()).migrations);
 
+    migration_table_add_mass_migration!(
+    /// Add a row to the migration table recording a mass migration event.
+    ///
+    /// See [`crate::OwningMigrationTable::add_mass_migration`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// assert!(tables.add_mass_migration(53.5, 0, 1, (0., 100.), 3).is_ok());
+    /// // A population cannot migrate to itself
+    /// assert!(tables.add_mass_migration(53.5, 1, 1, (0., 100.), 3).is_err());
+    /// ```
+    => add_mass_migration, self, &mut (*self.as_mut_ptr()).migrations);
+
     migration_table_add_row_with_metadata!(
     /// Add a row with optional metadata to the migration table
     ///
@@ -494,6 +777,56 @@ impl TableCollection {
     /// ```
     => add_mutation_with_metadata, self, &mut (*self.as_mut_ptr()).mutations);
 
+    /// Add a mutation above `node` at `position`.
+    ///
+    /// If a site already exists at `position`, the mutation is
+    /// added to that site.
+    /// Otherwise, a new site with no ancestral state is added.
+    ///
+    /// The mutation's parent is [`MutationId::NULL`](crate::MutationId::NULL).
+    /// If `time` is `None`, the mutation time is unknown.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if adding the site or mutation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// let m0 = tables.place_mutation(0, 5., b"A", None).unwrap();
+    /// let m1 = tables.place_mutation(1, 5., b"T", Some(0.5.into())).unwrap();
+    /// assert_ne!(m0, m1);
+    /// assert_eq!(tables.sites().num_rows(), 1);
+    /// assert_eq!(tables.mutations().site(m1), Some(0.into()));
+    /// ```
+    pub fn place_mutation<N: Into<NodeId>, P: Into<Position>>(
+        &mut self,
+        node: N,
+        position: P,
+        derived_state: &[u8],
+        time: Option<crate::Time>,
+    ) -> Result<crate::MutationId, TskitError> {
+        let position = f64::from(position.into());
+        let existing = self
+            .sites()
+            .position_slice_raw()
+            .iter()
+            .position(|&p| p == position);
+        let site = match existing {
+            Some(index) => crate::SiteId::from(index as tsk_id_t),
+            None => self.add_site(position, None)?,
+        };
+        let time = time.unwrap_or_else(|| f64::from_bits(ll_bindings::TSK_UNKNOWN_TIME_HEX).into());
+        self.add_mutation(
+            site,
+            node,
+            crate::MutationId::NULL,
+            time,
+            Some(derived_state),
+        )
+    }
+
     population_table_add_row!(
     /// Add a row to the population_table
     ///
@@ -577,6 +910,208 @@ impl TableCollection {
         }
     }
 
+    /// Set the edge insertion and removal orders directly.
+    ///
+    /// This replaces any existing indexes and avoids the cost of
+    /// [`TableCollection::build_index`] when the orders are already known.
+    ///
+    /// # Note
+    ///
+    /// Only the lengths of the orders and that each is a permutation of
+    /// the edge ids are checked.
+    /// It is the caller's responsibility to ensure that the orders are
+    /// the same as those that [`TableCollection::build_index`] would produce.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::ValueError`] if the length of either order differs
+    ///   from the number of edges, or if either order is not a
+    ///   permutation of the edge ids.
+    /// * [`TskitError::ErrorCode`] if the C library is unable to allocate
+    ///   the indexes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let insertion = tables.edge_insertion_order().unwrap().to_vec();
+    /// let removal = tables.edge_removal_order().unwrap().to_vec();
+    /// tables.set_indexes(&insertion, &removal).unwrap();
+    /// assert!(tables.is_indexed());
+    /// assert!(tables.set_indexes(&insertion, &insertion[..1]).is_err());
+    /// ```
+    pub fn set_indexes(&mut self, insertion: &[EdgeId], removal: &[EdgeId]) -> TskReturnValue {
+        let num_edges = self.edges().num_rows().as_usize();
+        for order in [insertion, removal] {
+            let mut seen = vec![false; num_edges];
+            let is_permutation = order.len() == num_edges
+                && order.iter().all(|e| match usize::try_from(*e) {
+                    Ok(index) if index < num_edges && !seen[index] => {
+                        seen[index] = true;
+                        true
+                    }
+                    _ => false,
+                });
+            if !is_permutation {
+                return Err(TskitError::ValueError {
+                    got: format!("{:?}", order),
+                    expected: format!("a permutation of the {} edge ids", num_edges),
+                });
+            }
+        }
+        // SAFETY: self pointer is not null, the orders have
+        // length num_edges, and the C API only reads them.
+        let rv = unsafe {
+            ll_bindings::tsk_table_collection_set_indexes(
+                self.as_mut_ptr(),
+                insertion.as_ptr() as *mut tsk_id_t,
+                removal.as_ptr() as *mut tsk_id_t,
+            )
+        };
+        handle_tsk_return_value!(rv)
+    }
+
+    /// Set the JSON metadata schemas of several tables at once.
+    ///
+    /// Tables whose entry in `schemas` is `None` are unchanged.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`] if any schema is not well-formed JSON.
+    /// The error names the table, and no schemas are changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// let schemas = tskit::metadata::MetadataSchemaSet {
+    ///     nodes: Some(r#"{"codec":"json"}"#.to_string()),
+    ///     ..Default::default()
+    /// };
+    /// tables.set_all_json_metadata_schemas(&schemas).unwrap();
+    /// assert_eq!(tables.metadata_schemas(), schemas);
+    ///
+    /// let bad = tskit::metadata::MetadataSchemaSet {
+    ///     sites: Some(r#"{"codec":}"#.to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert!(tables.set_all_json_metadata_schemas(&bad).is_err());
+    /// ```
+    pub fn set_all_json_metadata_schemas(
+        &mut self,
+        schemas: &crate::metadata::MetadataSchemaSet,
+    ) -> Result<(), TskitError> {
+        let tables = [
+            ("edge", &schemas.edges),
+            ("individual", &schemas.individuals),
+            ("migration", &schemas.migrations),
+            ("mutation", &schemas.mutations),
+            ("node", &schemas.nodes),
+            ("population", &schemas.populations),
+            ("site", &schemas.sites),
+        ];
+        for (table, schema) in tables {
+            if let Some(schema) = schema {
+                if serde_json::from_str::<serde_json::Value>(schema).is_err() {
+                    return Err(TskitError::ValueError {
+                        got: format!("malformed JSON in the {} table metadata schema", table),
+                        expected: "a well-formed JSON metadata schema".to_string(),
+                    });
+                }
+            }
+        }
+
+        macro_rules! set_schema {
+            ($schema: expr, $table: ident, $setter: ident) => {
+                if let Some(schema) = $schema {
+                    // SAFETY: self pointer is not null and the C API
+                    // copies the schema.
+                    let rv = unsafe {
+                        ll_bindings::$setter(
+                            &mut (*self.as_mut_ptr()).$table,
+                            schema.as_ptr().cast::<libc::c_char>(),
+                            schema.len() as tsk_size_t,
+                        )
+                    };
+                    if rv < 0 {
+                        return Err(TskitError::ErrorCode { code: rv });
+                    }
+                }
+            };
+        }
+        set_schema!(&schemas.edges, edges, tsk_edge_table_set_metadata_schema);
+        set_schema!(
+            &schemas.individuals,
+            individuals,
+            tsk_individual_table_set_metadata_schema
+        );
+        set_schema!(
+            &schemas.migrations,
+            migrations,
+            tsk_migration_table_set_metadata_schema
+        );
+        set_schema!(
+            &schemas.mutations,
+            mutations,
+            tsk_mutation_table_set_metadata_schema
+        );
+        set_schema!(&schemas.nodes, nodes, tsk_node_table_set_metadata_schema);
+        set_schema!(
+            &schemas.populations,
+            populations,
+            tsk_population_table_set_metadata_schema
+        );
+        set_schema!(&schemas.sites, sites, tsk_site_table_set_metadata_schema);
+        Ok(())
+    }
+
+    /// Get the metadata schemas of the tables.
+    ///
+    /// Tables with an empty schema are `None` in the returned value.
+    ///
+    /// See [`TableCollection::set_all_json_metadata_schemas`].
+    pub fn metadata_schemas(&self) -> crate::metadata::MetadataSchemaSet {
+        // SAFETY: self pointer is not null
+        let tables = unsafe { &*self.as_ptr() };
+        let schema = |schema: *const libc::c_char, length: tsk_size_t| match length {
+            0 => None,
+            n => Some(
+                String::from_utf8_lossy(crate::sys::generate_slice::<_, _, u8>(schema, n))
+                    .into_owned(),
+            ),
+        };
+        crate::metadata::MetadataSchemaSet {
+            edges: schema(
+                tables.edges.metadata_schema,
+                tables.edges.metadata_schema_length,
+            ),
+            individuals: schema(
+                tables.individuals.metadata_schema,
+                tables.individuals.metadata_schema_length,
+            ),
+            migrations: schema(
+                tables.migrations.metadata_schema,
+                tables.migrations.metadata_schema_length,
+            ),
+            mutations: schema(
+                tables.mutations.metadata_schema,
+                tables.mutations.metadata_schema_length,
+            ),
+            nodes: schema(
+                tables.nodes.metadata_schema,
+                tables.nodes.metadata_schema_length,
+            ),
+            populations: schema(
+                tables.populations.metadata_schema,
+                tables.populations.metadata_schema_length,
+            ),
+            sites: schema(
+                tables.sites.metadata_schema,
+                tables.sites.metadata_schema_length,
+            ),
+        }
+    }
+
     /// Sort the tables.  
     /// The [``bookmark``](crate::types::Bookmark) can
     /// be used to affect where sorting starts from for each table.
@@ -624,6 +1159,59 @@ impl TableCollection {
         self.sort(&b, options)
     }
 
+    /// Sort the mutation table, leaving the edge and migration tables unchanged.
+    ///
+    /// Mutations are sorted by site and then so that parent mutations come
+    /// before their children, as required by the `tskit` data model.
+    /// The site table is also sorted by position, which does not change
+    /// an already-sorted site table.
+    ///
+    /// If the tables were indexed, the indexes are rebuilt.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the tables fail integrity checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// tables.add_node(0, 0.0, -1, -1).unwrap();
+    /// let s0 = tables.add_site(1., Some(b"A")).unwrap();
+    /// let s1 = tables.add_site(2., Some(b"A")).unwrap();
+    /// tables.add_mutation(s1, 0, -1, 0.0, Some(b"G")).unwrap();
+    /// tables.add_mutation(s0, 0, -1, 0.0, Some(b"T")).unwrap();
+    /// tables.sort_mutations().unwrap();
+    /// assert_eq!(tables.mutations().site(0), Some(s0));
+    /// assert_eq!(tables.mutations().site(1), Some(s1));
+    /// ```
+    pub fn sort_mutations(&mut self) -> TskReturnValue {
+        let was_indexed = self.is_indexed();
+        let mut start = Bookmark::new();
+        start.set_migrations(self.migrations().num_rows());
+        let mut sorter = std::mem::MaybeUninit::<ll_bindings::tsk_table_sorter_t>::uninit();
+        // SAFETY: self pointer is not null and init zeroes the sorter
+        // before it can fail, so freeing it is always safe.
+        let rv = unsafe {
+            let rv = ll_bindings::tsk_table_sorter_init(sorter.as_mut_ptr(), self.as_mut_ptr(), 0);
+            if rv == 0 {
+                // Skip sorting edges
+                (*sorter.as_mut_ptr()).sort_edges = None;
+                ll_bindings::tsk_table_sorter_run(sorter.as_mut_ptr(), &start.offsets)
+            } else {
+                rv
+            }
+        };
+        unsafe { ll_bindings::tsk_table_sorter_free(sorter.as_mut_ptr()) };
+        if rv < 0 {
+            return Err(TskitError::ErrorCode { code: rv });
+        }
+        match was_indexed {
+            true => self.build_index(),
+            false => Ok(rv),
+        }
+    }
+
     /// Sorts the individual table in place, so that parents come before children,
     /// and the parent column is remapped as required. Node references to individuals
     /// are also updated.
@@ -713,6 +1301,423 @@ impl TableCollection {
         handle_tsk_return_value!(rv)
     }
 
+    /// Clear the contents of the mutation table.
+    /// Does not release memory.
+    ///
+    /// All other tables are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// let site = tables.add_site(1., None).unwrap();
+    /// tables.add_mutation(site, 0, tskit::MutationId::NULL, 0.0, None).unwrap();
+    /// tables.clear_mutations().unwrap();
+    /// assert_eq!(tables.mutations().num_rows(), 0);
+    /// assert_eq!(tables.sites().num_rows(), 1);
+    /// ```
+    pub fn clear_mutations(&mut self) -> TskReturnValue {
+        // SAFETY: self pointer is not null
+        let rv = unsafe { ll_bindings::tsk_mutation_table_clear(self.inner.mutations_mut()) };
+        handle_tsk_return_value!(rv)
+    }
+
+    /// Clear the contents of the site table.
+    /// Does not release memory.
+    ///
+    /// Because mutations refer to sites, the mutation table is
+    /// also cleared.
+    /// All other tables are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// let site = tables.add_site(1., None).unwrap();
+    /// tables.add_mutation(site, 0, tskit::MutationId::NULL, 0.0, None).unwrap();
+    /// tables.clear_sites().unwrap();
+    /// assert_eq!(tables.mutations().num_rows(), 0);
+    /// assert_eq!(tables.sites().num_rows(), 0);
+    /// ```
+    pub fn clear_sites(&mut self) -> TskReturnValue {
+        self.clear_mutations()?;
+        // SAFETY: self pointer is not null
+        let rv = unsafe { ll_bindings::tsk_site_table_clear(self.inner.sites_mut()) };
+        handle_tsk_return_value!(rv)
+    }
+
+    /// Multiply all node, mutation, and migration times by a constant.
+    ///
+    /// Unknown mutation times are left unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `factor`: the scaling factor.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`] if `factor` is not a finite
+    /// value greater than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// tables.add_node(0, 10.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// // Convert from generations to years
+    /// tables.rescale_times(25.0).unwrap();
+    /// assert_eq!(tables.nodes().time(0).unwrap(), 250.0);
+    /// assert!(tables.rescale_times(0.0).is_err());
+    /// ```
+    pub fn rescale_times(&mut self, factor: f64) -> Result<(), TskitError> {
+        if !factor.is_finite() || factor <= 0.0 {
+            return Err(TskitError::ValueError {
+                got: factor.to_string(),
+                expected: "factor > 0.0".to_string(),
+            });
+        }
+        for time in self.nodes_mut().time_slice_mut() {
+            *time = (f64::from(*time) * factor).into();
+        }
+        let mutations = self.inner.mutations_mut();
+        let time: &mut [f64] = crate::sys::generate_slice_mut(mutations.time, mutations.num_rows);
+        for t in time.iter_mut().filter(|t| !t.is_nan()) {
+            *t *= factor;
+        }
+        let migrations = self.inner.migrations_mut();
+        let time: &mut [f64] = crate::sys::generate_slice_mut(migrations.time, migrations.num_rows);
+        for t in time {
+            *t *= factor;
+        }
+        Ok(())
+    }
+
+    /// Add a constant to all genomic coordinates.
+    ///
+    /// Edge and migration intervals and site positions are shifted
+    /// by `offset` and the sequence length is increased by `offset`.
+    ///
+    /// # Parameters
+    ///
+    /// * `offset`: the shift to apply.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::RangeError`] if a negative `offset` would result
+    /// in a coordinate less than zero or in a sequence length that
+    /// is not greater than zero.
+    /// In this case, the tables are not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// tables.add_edge(10., 20., 0, 1).unwrap();
+    /// tables.add_site(15., None).unwrap();
+    /// tables.shift_coordinates(100.).unwrap();
+    /// assert_eq!(tables.sequence_length(), 200.);
+    /// assert_eq!(tables.edges().left(0).unwrap(), 110.);
+    /// assert_eq!(tables.edges().right(0).unwrap(), 120.);
+    /// assert_eq!(tables.sites().position(0).unwrap(), 115.);
+    /// assert!(tables.shift_coordinates(-111.).is_err());
+    /// ```
+    pub fn shift_coordinates<P: Into<Position>>(&mut self, offset: P) -> Result<(), TskitError> {
+        let offset = f64::from(offset.into());
+        let edges = self.inner.edges_mut();
+        let edges_left: &mut [f64] = crate::sys::generate_slice_mut(edges.left, edges.num_rows);
+        let edges_right: &mut [f64] = crate::sys::generate_slice_mut(edges.right, edges.num_rows);
+        let sites = self.inner.sites_mut();
+        let positions: &mut [f64] = crate::sys::generate_slice_mut(sites.position, sites.num_rows);
+        let migrations = self.inner.migrations_mut();
+        let migrations_left: &mut [f64] =
+            crate::sys::generate_slice_mut(migrations.left, migrations.num_rows);
+        let migrations_right: &mut [f64] =
+            crate::sys::generate_slice_mut(migrations.right, migrations.num_rows);
+
+        let sequence_length = f64::from(self.sequence_length()) + offset;
+        let min_coordinate = edges_left
+            .iter()
+            .chain(positions.iter())
+            .chain(migrations_left.iter())
+            .fold(f64::INFINITY, |a, &b| a.min(b));
+        if sequence_length <= 0.0 || min_coordinate + offset < 0.0 {
+            return Err(TskitError::RangeError(format!(
+                "shifting coordinates by {} results in values out of range",
+                offset
+            )));
+        }
+
+        for x in edges_left
+            .iter_mut()
+            .chain(edges_right.iter_mut())
+            .chain(positions.iter_mut())
+            .chain(migrations_left.iter_mut())
+            .chain(migrations_right.iter_mut())
+        {
+            *x += offset;
+        }
+        // SAFETY: self pointer is not null
+        unsafe { (*self.as_mut_ptr()).sequence_length = sequence_length };
+        Ok(())
+    }
+
+    /// Remove the portion of the genealogy older than `time`.
+    ///
+    /// For each edge whose parent is older than `time`:
+    ///
+    /// * If the child is younger than `time`, the edge is
+    ///   repointed to a new root node at `time`.
+    ///   A single new node is created for each such child,
+    ///   and it is shared by all of the child's edges that
+    ///   are truncated.
+    ///   New nodes have empty flags and no population or individual.
+    /// * Otherwise, the edge is removed.
+    ///
+    /// Mutations on nodes older than `time` and mutations with
+    /// known times older than `time` are removed.
+    /// Retained mutations whose parent mutation is removed
+    /// have their parent set to [`MutationId::NULL`](crate::MutationId::NULL).
+    /// Migrations older than `time` are removed.
+    ///
+    /// No nodes are removed.
+    ///
+    /// # Notes
+    ///
+    /// The edge table index is dropped and the edges are no longer sorted.
+    /// The tables must be sorted and indexed before creating a
+    /// [`TreeSequence`](crate::TreeSequence).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// let root = tables.add_node(0, 2.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// let sample = tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_edge(0., 100., root, sample).unwrap();
+    /// tables.decapitate(1.0).unwrap();
+    /// assert_eq!(tables.nodes().num_rows(), 3);
+    /// assert_eq!(tables.nodes().time(2).unwrap(), 1.0);
+    /// assert_eq!(tables.edges().parent(0).unwrap(), 2);
+    /// assert_eq!(tables.edges().child(0).unwrap(), sample);
+    /// ```
+    pub fn decapitate<T: Into<crate::Time>>(&mut self, time: T) -> Result<(), TskitError> {
+        // Ensures that all node and mutation ids are valid
+        self.check_integrity(TableIntegrityCheckFlags::default())?;
+        let time = f64::from(time.into());
+        let node_time = self
+            .nodes()
+            .time_slice()
+            .iter()
+            .map(|t| f64::from(*t))
+            .collect::<Vec<_>>();
+        let edges = self.inner.edges_mut();
+        let edge_parent: &[tsk_id_t] = crate::sys::generate_slice(edges.parent, edges.num_rows);
+        let edge_child: &[tsk_id_t] = crate::sys::generate_slice(edges.child, edges.num_rows);
+        let mut new_parents = edge_parent.to_vec();
+        let edge_child = edge_child.to_vec();
+
+        let mut new_nodes = vec![NodeId::NULL; node_time.len()];
+        let mut keep_edges = vec![1 as ll_bindings::tsk_bool_t; new_parents.len()];
+        for (i, &child) in edge_child.iter().enumerate() {
+            let child = child as usize;
+            if node_time[new_parents[i] as usize] <= time {
+                continue;
+            }
+            if node_time[child] >= time {
+                keep_edges[i] = 0;
+                continue;
+            }
+            if new_nodes[child].is_null() {
+                new_nodes[child] = self.add_node(
+                    0,
+                    time,
+                    crate::PopulationId::NULL,
+                    crate::IndividualId::NULL,
+                )?;
+            }
+            new_parents[i] = new_nodes[child].into();
+        }
+
+        let edges = self.inner.edges_mut();
+        let edge_parent: &mut [tsk_id_t] =
+            crate::sys::generate_slice_mut(edges.parent, edges.num_rows);
+        edge_parent.copy_from_slice(&new_parents);
+        // SAFETY: self pointer is not null and keep has length num_rows
+        let rv = unsafe {
+            ll_bindings::tsk_edge_table_keep_rows(
+                edges,
+                keep_edges.as_ptr(),
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        if rv < 0 {
+            return Err(TskitError::ErrorCode { code: rv });
+        }
+
+        let mutations = self.inner.mutations_mut();
+        let mutation_node: &[tsk_id_t] =
+            crate::sys::generate_slice(mutations.node, mutations.num_rows);
+        let mutation_time: &[f64] = crate::sys::generate_slice(mutations.time, mutations.num_rows);
+        let keep_mutations = mutation_node
+            .iter()
+            .zip(mutation_time.iter())
+            .map(|(&node, &t)| {
+                let remove = node_time[node as usize] > time || (!t.is_nan() && t > time);
+                ll_bindings::tsk_bool_t::from(!remove)
+            })
+            .collect::<Vec<_>>();
+        let mutation_parent: &mut [tsk_id_t] =
+            crate::sys::generate_slice_mut(mutations.parent, mutations.num_rows);
+        for parent in mutation_parent.iter_mut() {
+            if *parent != ll_bindings::TSK_NULL && keep_mutations[*parent as usize] == 0 {
+                *parent = ll_bindings::TSK_NULL;
+            }
+        }
+        // SAFETY: self pointer is not null and keep has length num_rows
+        let rv = unsafe {
+            ll_bindings::tsk_mutation_table_keep_rows(
+                mutations,
+                keep_mutations.as_ptr(),
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        if rv < 0 {
+            return Err(TskitError::ErrorCode { code: rv });
+        }
+
+        let migrations = self.inner.migrations_mut();
+        let migration_time: &[f64] =
+            crate::sys::generate_slice(migrations.time, migrations.num_rows);
+        let keep_migrations = migration_time
+            .iter()
+            .map(|&t| ll_bindings::tsk_bool_t::from(t <= time))
+            .collect::<Vec<_>>();
+        // SAFETY: self pointer is not null and keep has length num_rows
+        let rv = unsafe {
+            ll_bindings::tsk_migration_table_keep_rows(
+                migrations,
+                keep_migrations.as_ptr(),
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        if rv < 0 {
+            return Err(TskitError::ErrorCode { code: rv });
+        }
+
+        // SAFETY: self pointer is not null
+        let rv = unsafe { ll_bindings::tsk_table_collection_drop_index(self.as_mut_ptr(), 0) };
+        handle_tsk_return_value!(rv, ())
+    }
+
+    /// Get the nodes that are neither the parent nor the child of any edge.
+    ///
+    /// Sample nodes are included.
+    ///
+    /// Edge parents and children that are null or not in the
+    /// node table are ignored, but the other node of such an edge
+    /// is not isolated.
+    /// Use [`TableCollection::check_integrity`] to find these edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// let root = tables.add_node(0, 1.0, -1, -1).unwrap();
+    /// let sample = tables.add_node(tskit::NodeFlags::new_sample(), 0.0, -1, -1).unwrap();
+    /// let orphan = tables.add_node(tskit::NodeFlags::new_sample(), 0.0, -1, -1).unwrap();
+    /// tables.add_edge(0., 100., root, sample).unwrap();
+    /// assert_eq!(tables.isolated_nodes(), vec![orphan]);
+    /// ```
+    pub fn isolated_nodes(&self) -> Vec<NodeId> {
+        let mut connected = vec![false; self.nodes().num_rows().as_usize()];
+        let edges = self.edges();
+        for u in edges.parent_slice().iter().chain(edges.child_slice()) {
+            if let Some(c) = u.to_usize().and_then(|u| connected.get_mut(u)) {
+                *c = true;
+            }
+        }
+        connected
+            .iter()
+            .enumerate()
+            .filter(|(_, &c)| !c)
+            .map(|(u, _)| NodeId::from(u as tsk_id_t))
+            .collect()
+    }
+
+    /// Remove duplicate rows from the edge table.
+    ///
+    /// Edges are duplicates if they have the same left, right,
+    /// parent, and child.
+    /// The first of each set of duplicates is kept, along with its
+    /// metadata, and the order of the remaining edges is preserved.
+    /// Unlike `tsk_edge_table_squash` in the C library, edges with
+    /// abutting intervals are not merged.
+    ///
+    /// Returns the number of edges removed.
+    ///
+    /// # Notes
+    ///
+    /// If any edges are removed, the edge table index is dropped.
+    /// The tables must be indexed before creating a
+    /// [`TreeSequence`](crate::TreeSequence).
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library returns an error code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// tables.add_edge(0., 100., 0, 1).unwrap();
+    /// tables.add_edge(0., 50., 0, 2).unwrap();
+    /// tables.add_edge(0., 100., 0, 1).unwrap();
+    /// assert_eq!(tables.merge_duplicate_edges().unwrap(), 1);
+    /// assert_eq!(tables.edges().num_rows(), 2);
+    /// assert_eq!(tables.edges().child(1).unwrap(), 2);
+    /// ```
+    pub fn merge_duplicate_edges(&mut self) -> Result<usize, TskitError> {
+        let edges = self.edges();
+        let mut seen = std::collections::HashSet::new();
+        let keep = edges
+            .left_slice()
+            .iter()
+            .zip(edges.right_slice())
+            .zip(edges.parent_slice().iter().zip(edges.child_slice()))
+            .map(|((left, right), (parent, child))| {
+                let key = (
+                    f64::from(*left).to_bits(),
+                    f64::from(*right).to_bits(),
+                    *parent,
+                    *child,
+                );
+                seen.insert(key) as ll_bindings::tsk_bool_t
+            })
+            .collect::<Vec<_>>();
+        let num_removed = keep.iter().filter(|&&k| k == 0).count();
+        if num_removed == 0 {
+            return Ok(0);
+        }
+        // SAFETY: self pointer is not null and keep has length num_rows
+        let rv = unsafe {
+            ll_bindings::tsk_edge_table_keep_rows(
+                self.inner.edges_mut(),
+                keep.as_ptr(),
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        if rv < 0 {
+            return Err(TskitError::ErrorCode { code: rv });
+        }
+        // SAFETY: self pointer is not null
+        let rv = unsafe { ll_bindings::tsk_table_collection_drop_index(self.as_mut_ptr(), 0) };
+        handle_tsk_return_value!(rv, num_removed)
+    }
+
     /// Free all memory allocated on the C side.
     /// Not public b/c not very safe.
     #[allow(dead_code)]
@@ -738,6 +1743,82 @@ impl TableCollection {
         }
     }
 
+    /// Compare ``self`` to ``other``, reporting which tables differ.
+    ///
+    /// Rows are compared using the row types returned by
+    /// the table iterators.
+    /// The time units, metadata, metadata schemas, and reference
+    /// sequence are also compared, as in [`TableCollection::equals`].
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: a [`TableEqualityOptions`] bit field.
+    ///   If [`TableEqualityOptions::IGNORE_METADATA`] is set, metadata
+    ///   and metadata schemas are not compared.
+    ///   If [`TableEqualityOptions::IGNORE_TS_METADATA`] is set, the
+    ///   metadata of the table collection and its schema are not compared.
+    ///   If the `provenance` feature is enabled, then
+    ///   [`TableEqualityOptions::IGNORE_PROVENANCE`] and
+    ///   [`TableEqualityOptions::IGNORE_TIMESTAMPS`] are respected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// tables.add_edge(0., 100., 0, 1).unwrap();
+    /// let mut other = tables.deepcopy().unwrap();
+    /// assert!(tables.diff(&other, tskit::TableEqualityOptions::default()).is_empty());
+    ///
+    /// other.add_edge(0., 100., 0, 2).unwrap();
+    /// let diff = tables.diff(&other, tskit::TableEqualityOptions::default());
+    /// assert!(!diff.is_empty());
+    /// assert_eq!(diff.edges, Some(1.into()));
+    /// assert!(diff.nodes.is_none());
+    /// ```
+    pub fn diff<O: Into<TableEqualityOptions>>(
+        &self,
+        other: &TableCollection,
+        options: O,
+    ) -> crate::TableDiff {
+        crate::TableDiff::new(self, other, options.into())
+    }
+
+    /// Return ``true`` if ``self`` contains the same data as ``other``,
+    /// allowing for small differences in floating point values.
+    ///
+    /// The sequence length and the [`Time`](crate::Time) and
+    /// [`Position`](crate::Position) columns of the edge, node, site,
+    /// mutation, and migration tables are compared using `tolerance`.
+    /// Everything else is compared as in [`TableCollection::equals`].
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if `other` cannot be copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tskit::FloatTolerance;
+    ///
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// tables.add_node(0, 0.1 + 0.2, -1, -1).unwrap();
+    /// let mut other = tskit::TableCollection::new(100.).unwrap();
+    /// other.add_node(0, 0.3, -1, -1).unwrap();
+    /// let options = tskit::TableEqualityOptions::default();
+    /// assert!(!tables.equals(&other, options));
+    /// assert!(tables.approx_equals(&other, options, FloatTolerance::Absolute(1e-12)).unwrap());
+    /// assert!(tables.approx_equals(&other, options, FloatTolerance::Relative(1e-12)).unwrap());
+    /// assert!(!tables.approx_equals(&other, options, FloatTolerance::Absolute(0.0)).unwrap());
+    /// ```
+    pub fn approx_equals<O: Into<TableEqualityOptions>>(
+        &self,
+        other: &TableCollection,
+        options: O,
+        tolerance: crate::FloatTolerance,
+    ) -> Result<bool, TskitError> {
+        crate::table_diff::approx_equals(self, other, options.into(), tolerance)
+    }
+
     /// Return a "deep" copy of the tables.
     pub fn deepcopy(&self) -> Result<TableCollection, TskitError> {
         let (rv, inner) = self.inner.copy();
@@ -748,6 +1829,13 @@ impl TableCollection {
     /// Return a [`crate::TreeSequence`] based on the tables.
     /// This function will raise errors if tables are not sorted,
     /// not indexed, or invalid in any way.
+    ///
+    /// # Note
+    ///
+    /// The integrity checks cannot be skipped.
+    /// The C library runs them unconditionally when initializing
+    /// a tree sequence because they are also used to count the
+    /// number of trees.
     pub fn tree_sequence(
         self,
         flags: TreeSequenceFlags,
@@ -1215,8 +2303,14 @@ impl TableCollection {
 
     delegate! {
         to self.views {
+            /// Get mutable reference to the [``EdgeTable``](crate::EdgeTable).
+            pub fn edges_mut(&mut self) -> &mut crate::EdgeTable;
             /// Get mutable reference to the [``NodeTable``](crate::NodeTable).
             pub fn nodes_mut(&mut self) -> &mut crate::NodeTable;
+            /// Get mutable reference to the [``SiteTable``](crate::SiteTable).
+            pub fn sites_mut(&mut self) -> &mut crate::SiteTable;
+            /// Get mutable reference to the [``MutationTable``](crate::MutationTable).
+            pub fn mutations_mut(&mut self) -> &mut crate::MutationTable;
         }
     }
 
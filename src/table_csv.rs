@@ -0,0 +1,580 @@
+//! Reading and writing tables as comma-separated values.
+//!
+//! Each table is written with a header line naming its columns,
+//! followed by one line per row:
+//!
+//! * The first column is the row id.
+//! * Ids are written as integers, with `-1` for null ids.
+//! * Floating point values are written in a format that is
+//!   read back without loss of precision.
+//! * Byte columns such as metadata are written as lowercase hexadecimal.
+//!   Missing values are empty fields.
+//! * Ragged columns of individuals are separated by `;`.
+//! * The free text of provenances is also written as hexadecimal,
+//!   so it may contain commas and line breaks.
+
+use crate::sys::bindings as ll_bindings;
+use crate::EdgeTable;
+use crate::IndividualTable;
+use crate::MigrationTable;
+use crate::MutationTable;
+use crate::NodeTable;
+use crate::OwningEdgeTable;
+use crate::OwningIndividualTable;
+use crate::OwningMigrationTable;
+use crate::OwningMutationTable;
+use crate::OwningNodeTable;
+use crate::OwningPopulationTable;
+use crate::OwningSiteTable;
+use crate::PopulationTable;
+use crate::SiteTable;
+use crate::TskitError;
+use ll_bindings::tsk_id_t;
+use ll_bindings::tsk_size_t;
+use std::io::BufRead;
+use std::io::Write;
+
+const EDGE_COLUMNS: &[&str] = &["id", "left", "right", "parent", "child", "metadata"];
+const NODE_COLUMNS: &[&str] = &[
+    "id",
+    "flags",
+    "time",
+    "population",
+    "individual",
+    "metadata",
+];
+const SITE_COLUMNS: &[&str] = &["id", "position", "ancestral_state", "metadata"];
+const MUTATION_COLUMNS: &[&str] = &[
+    "id",
+    "site",
+    "node",
+    "parent",
+    "time",
+    "derived_state",
+    "metadata",
+];
+const INDIVIDUAL_COLUMNS: &[&str] = &["id", "flags", "location", "parents", "metadata"];
+const POPULATION_COLUMNS: &[&str] = &["id", "metadata"];
+const MIGRATION_COLUMNS: &[&str] = &[
+    "id", "left", "right", "node", "source", "dest", "time", "metadata",
+];
+#[cfg(feature = "provenance")]
+const PROVENANCE_COLUMNS: &[&str] = &["id", "timestamp", "record"];
+
+fn io_error(error: std::io::Error) -> TskitError {
+    TskitError::LibraryError(error.to_string())
+}
+
+fn write_header<W: Write>(writer: &mut W, columns: &[&str]) -> Result<(), TskitError> {
+    writeln!(writer, "{}", columns.join(",")).map_err(io_error)
+}
+
+fn write_record<W: Write>(writer: &mut W, fields: &[String]) -> Result<(), TskitError> {
+    writeln!(writer, "{}", fields.join(",")).map_err(io_error)
+}
+
+fn float(value: f64) -> String {
+    // Debug formatting of f64 round-trips through str::parse
+    format!("{:?}", value)
+}
+
+fn hex_bytes(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hex(data: &Option<Vec<u8>>) -> String {
+    match data {
+        Some(data) => hex_bytes(data),
+        None => String::new(),
+    }
+}
+
+fn check_row(rv: tsk_id_t) -> Result<(), TskitError> {
+    match rv {
+        rv if rv < 0 => Err(TskitError::ErrorCode { code: rv }),
+        _ => Ok(()),
+    }
+}
+
+fn as_raw_parts(data: &[u8]) -> (*const libc::c_char, tsk_size_t) {
+    match data.is_empty() {
+        true => (std::ptr::null(), 0),
+        false => (
+            data.as_ptr().cast::<libc::c_char>(),
+            data.len() as tsk_size_t,
+        ),
+    }
+}
+
+struct Record {
+    fields: Vec<String>,
+    line: usize,
+}
+
+impl Record {
+    fn invalid(&self, column: usize, expected: &str) -> TskitError {
+        TskitError::ValueError {
+            got: format!(
+                "{:?} in column {} of line {}",
+                self.fields[column], column, self.line
+            ),
+            expected: expected.to_string(),
+        }
+    }
+
+    fn parse<T: std::str::FromStr>(&self, column: usize, expected: &str) -> Result<T, TskitError> {
+        self.fields[column]
+            .parse::<T>()
+            .map_err(|_| self.invalid(column, expected))
+    }
+
+    fn id(&self, column: usize) -> Result<tsk_id_t, TskitError> {
+        self.parse(column, "an id")
+    }
+
+    fn float(&self, column: usize) -> Result<f64, TskitError> {
+        self.parse(column, "a floating point value")
+    }
+
+    fn bytes(&self, column: usize) -> Result<Vec<u8>, TskitError> {
+        let field = self.fields[column].as_bytes();
+        if field.len() % 2 != 0 {
+            return Err(self.invalid(column, "hexadecimal bytes"));
+        }
+        field
+            .chunks(2)
+            .map(|pair| {
+                std::str::from_utf8(pair)
+                    .ok()
+                    .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                    .ok_or_else(|| self.invalid(column, "hexadecimal bytes"))
+            })
+            .collect()
+    }
+
+    fn list<T: std::str::FromStr>(
+        &self,
+        column: usize,
+        expected: &str,
+    ) -> Result<Vec<T>, TskitError> {
+        match self.fields[column].is_empty() {
+            true => Ok(vec![]),
+            false => self.fields[column]
+                .split(';')
+                .map(|value| {
+                    value
+                        .parse::<T>()
+                        .map_err(|_| self.invalid(column, expected))
+                })
+                .collect(),
+        }
+    }
+}
+
+struct Reader<R: BufRead> {
+    lines: std::io::Lines<R>,
+    columns: &'static [&'static str],
+    line: usize,
+    num_rows: tsk_id_t,
+}
+
+impl<R: BufRead> Reader<R> {
+    fn new(reader: R, columns: &'static [&'static str]) -> Result<Self, TskitError> {
+        let mut lines = reader.lines();
+        let header = lines.next().transpose().map_err(io_error)?;
+        let expected = columns.join(",");
+        match header {
+            Some(header) if header.trim_end() == expected => Ok(Self {
+                lines,
+                columns,
+                line: 1,
+                num_rows: 0,
+            }),
+            header => Err(TskitError::ValueError {
+                got: format!("header {:?}", header.unwrap_or_default()),
+                expected: format!("header {:?}", expected),
+            }),
+        }
+    }
+
+    fn next_record(&mut self) -> Result<Option<Record>, TskitError> {
+        let line = match self.lines.next().transpose().map_err(io_error)? {
+            Some(line) => line,
+            None => return Ok(None),
+        };
+        self.line += 1;
+        let fields = line
+            .trim_end()
+            .split(',')
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>();
+        if fields.len() != self.columns.len() {
+            return Err(TskitError::ValueError {
+                got: format!("{} fields on line {}", fields.len(), self.line),
+                expected: format!("{} fields", self.columns.len()),
+            });
+        }
+        let record = Record {
+            fields,
+            line: self.line,
+        };
+        if record.id(0)? != self.num_rows {
+            return Err(record.invalid(0, &format!("row id {}", self.num_rows)));
+        }
+        self.num_rows += 1;
+        Ok(Some(record))
+    }
+}
+
+pub(crate) fn write_edges<W: Write>(table: &EdgeTable, writer: &mut W) -> Result<(), TskitError> {
+    write_header(writer, EDGE_COLUMNS)?;
+    for row in table.iter() {
+        write_record(
+            writer,
+            &[
+                tsk_id_t::from(row.id).to_string(),
+                float(row.left.into()),
+                float(row.right.into()),
+                tsk_id_t::from(row.parent).to_string(),
+                tsk_id_t::from(row.child).to_string(),
+                hex(&row.metadata),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_edges<R: BufRead>(reader: R) -> Result<OwningEdgeTable, TskitError> {
+    let mut table = OwningEdgeTable::default();
+    let mut reader = Reader::new(reader, EDGE_COLUMNS)?;
+    while let Some(record) = reader.next_record()? {
+        let metadata = record.bytes(5)?;
+        let (metadata, metadata_length) = as_raw_parts(&metadata);
+        // SAFETY: the table pointer is not null
+        check_row(unsafe {
+            ll_bindings::tsk_edge_table_add_row(
+                table.as_mut_ptr(),
+                record.float(1)?,
+                record.float(2)?,
+                record.id(3)?,
+                record.id(4)?,
+                metadata,
+                metadata_length,
+            )
+        })?;
+    }
+    Ok(table)
+}
+
+pub(crate) fn write_nodes<W: Write>(table: &NodeTable, writer: &mut W) -> Result<(), TskitError> {
+    write_header(writer, NODE_COLUMNS)?;
+    for row in table.iter() {
+        write_record(
+            writer,
+            &[
+                tsk_id_t::from(row.id).to_string(),
+                row.flags.bits().to_string(),
+                float(row.time.into()),
+                tsk_id_t::from(row.population).to_string(),
+                tsk_id_t::from(row.individual).to_string(),
+                hex(&row.metadata),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_nodes<R: BufRead>(reader: R) -> Result<OwningNodeTable, TskitError> {
+    let mut table = OwningNodeTable::default();
+    let mut reader = Reader::new(reader, NODE_COLUMNS)?;
+    while let Some(record) = reader.next_record()? {
+        let metadata = record.bytes(5)?;
+        let (metadata, metadata_length) = as_raw_parts(&metadata);
+        // SAFETY: the table pointer is not null
+        check_row(unsafe {
+            ll_bindings::tsk_node_table_add_row(
+                table.as_mut_ptr(),
+                record.parse(1, "node flags")?,
+                record.float(2)?,
+                record.id(3)?,
+                record.id(4)?,
+                metadata,
+                metadata_length,
+            )
+        })?;
+    }
+    Ok(table)
+}
+
+pub(crate) fn write_sites<W: Write>(table: &SiteTable, writer: &mut W) -> Result<(), TskitError> {
+    write_header(writer, SITE_COLUMNS)?;
+    for row in table.iter() {
+        write_record(
+            writer,
+            &[
+                tsk_id_t::from(row.id).to_string(),
+                float(row.position.into()),
+                hex(&row.ancestral_state),
+                hex(&row.metadata),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_sites<R: BufRead>(reader: R) -> Result<OwningSiteTable, TskitError> {
+    let mut table = OwningSiteTable::default();
+    let mut reader = Reader::new(reader, SITE_COLUMNS)?;
+    while let Some(record) = reader.next_record()? {
+        let ancestral_state = record.bytes(2)?;
+        let (ancestral_state, ancestral_state_length) = as_raw_parts(&ancestral_state);
+        let metadata = record.bytes(3)?;
+        let (metadata, metadata_length) = as_raw_parts(&metadata);
+        // SAFETY: the table pointer is not null
+        check_row(unsafe {
+            ll_bindings::tsk_site_table_add_row(
+                table.as_mut_ptr(),
+                record.float(1)?,
+                ancestral_state,
+                ancestral_state_length,
+                metadata,
+                metadata_length,
+            )
+        })?;
+    }
+    Ok(table)
+}
+
+pub(crate) fn write_mutations<W: Write>(
+    table: &MutationTable,
+    writer: &mut W,
+) -> Result<(), TskitError> {
+    write_header(writer, MUTATION_COLUMNS)?;
+    for row in table.iter() {
+        write_record(
+            writer,
+            &[
+                tsk_id_t::from(row.id).to_string(),
+                tsk_id_t::from(row.site).to_string(),
+                tsk_id_t::from(row.node).to_string(),
+                tsk_id_t::from(row.parent).to_string(),
+                float(row.time.into()),
+                hex(&row.derived_state),
+                hex(&row.metadata),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_mutations<R: BufRead>(reader: R) -> Result<OwningMutationTable, TskitError> {
+    let mut table = OwningMutationTable::default();
+    let mut reader = Reader::new(reader, MUTATION_COLUMNS)?;
+    while let Some(record) = reader.next_record()? {
+        // All NaN times are read back as unknown times
+        let time = match record.float(4)? {
+            time if time.is_nan() => f64::from_bits(ll_bindings::TSK_UNKNOWN_TIME_HEX),
+            time => time,
+        };
+        let derived_state = record.bytes(5)?;
+        let (derived_state, derived_state_length) = as_raw_parts(&derived_state);
+        let metadata = record.bytes(6)?;
+        let (metadata, metadata_length) = as_raw_parts(&metadata);
+        // SAFETY: the table pointer is not null
+        check_row(unsafe {
+            ll_bindings::tsk_mutation_table_add_row(
+                table.as_mut_ptr(),
+                record.id(1)?,
+                record.id(2)?,
+                record.id(3)?,
+                time,
+                derived_state,
+                derived_state_length,
+                metadata,
+                metadata_length,
+            )
+        })?;
+    }
+    Ok(table)
+}
+
+pub(crate) fn write_individuals<W: Write>(
+    table: &IndividualTable,
+    writer: &mut W,
+) -> Result<(), TskitError> {
+    write_header(writer, INDIVIDUAL_COLUMNS)?;
+    for row in table.iter() {
+        let location = row
+            .location
+            .unwrap_or_default()
+            .into_iter()
+            .map(|x| float(x.into()))
+            .collect::<Vec<_>>();
+        let parents = row
+            .parents
+            .unwrap_or_default()
+            .into_iter()
+            .map(|p| tsk_id_t::from(p).to_string())
+            .collect::<Vec<_>>();
+        write_record(
+            writer,
+            &[
+                tsk_id_t::from(row.id).to_string(),
+                row.flags.bits().to_string(),
+                location.join(";"),
+                parents.join(";"),
+                hex(&row.metadata),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_individuals<R: BufRead>(reader: R) -> Result<OwningIndividualTable, TskitError> {
+    let mut table = OwningIndividualTable::default();
+    let mut reader = Reader::new(reader, INDIVIDUAL_COLUMNS)?;
+    while let Some(record) = reader.next_record()? {
+        let location = record.list::<f64>(2, "floating point values")?;
+        let parents = record.list::<tsk_id_t>(3, "ids")?;
+        let metadata = record.bytes(4)?;
+        let (metadata, metadata_length) = as_raw_parts(&metadata);
+        // SAFETY: the table pointer is not null and the
+        // lengths are those of the vectors
+        check_row(unsafe {
+            ll_bindings::tsk_individual_table_add_row(
+                table.as_mut_ptr(),
+                record.parse(1, "individual flags")?,
+                location.as_ptr(),
+                location.len() as tsk_size_t,
+                parents.as_ptr(),
+                parents.len() as tsk_size_t,
+                metadata,
+                metadata_length,
+            )
+        })?;
+    }
+    Ok(table)
+}
+
+pub(crate) fn write_populations<W: Write>(
+    table: &PopulationTable,
+    writer: &mut W,
+) -> Result<(), TskitError> {
+    write_header(writer, POPULATION_COLUMNS)?;
+    for row in table.iter() {
+        write_record(
+            writer,
+            &[tsk_id_t::from(row.id).to_string(), hex(&row.metadata)],
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_populations<R: BufRead>(reader: R) -> Result<OwningPopulationTable, TskitError> {
+    let mut table = OwningPopulationTable::default();
+    let mut reader = Reader::new(reader, POPULATION_COLUMNS)?;
+    while let Some(record) = reader.next_record()? {
+        let metadata = record.bytes(1)?;
+        let (metadata, metadata_length) = as_raw_parts(&metadata);
+        // SAFETY: the table pointer is not null
+        check_row(unsafe {
+            ll_bindings::tsk_population_table_add_row(table.as_mut_ptr(), metadata, metadata_length)
+        })?;
+    }
+    Ok(table)
+}
+
+pub(crate) fn write_migrations<W: Write>(
+    table: &MigrationTable,
+    writer: &mut W,
+) -> Result<(), TskitError> {
+    write_header(writer, MIGRATION_COLUMNS)?;
+    for row in table.iter() {
+        write_record(
+            writer,
+            &[
+                tsk_id_t::from(row.id).to_string(),
+                float(row.left.into()),
+                float(row.right.into()),
+                tsk_id_t::from(row.node).to_string(),
+                tsk_id_t::from(row.source).to_string(),
+                tsk_id_t::from(row.dest).to_string(),
+                float(row.time.into()),
+                hex(&row.metadata),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_migrations<R: BufRead>(reader: R) -> Result<OwningMigrationTable, TskitError> {
+    let mut table = OwningMigrationTable::default();
+    let mut reader = Reader::new(reader, MIGRATION_COLUMNS)?;
+    while let Some(record) = reader.next_record()? {
+        let metadata = record.bytes(7)?;
+        let (metadata, metadata_length) = as_raw_parts(&metadata);
+        // SAFETY: the table pointer is not null
+        check_row(unsafe {
+            ll_bindings::tsk_migration_table_add_row(
+                table.as_mut_ptr(),
+                record.float(1)?,
+                record.float(2)?,
+                record.id(3)?,
+                record.id(4)?,
+                record.id(5)?,
+                record.float(6)?,
+                metadata,
+                metadata_length,
+            )
+        })?;
+    }
+    Ok(table)
+}
+
+#[cfg(feature = "provenance")]
+pub(crate) fn write_provenances<W: Write>(
+    table: &crate::provenance::ProvenanceTable,
+    writer: &mut W,
+) -> Result<(), TskitError> {
+    write_header(writer, PROVENANCE_COLUMNS)?;
+    for row in table.iter() {
+        write_record(
+            writer,
+            &[
+                tsk_id_t::from(row.id).to_string(),
+                hex_bytes(row.timestamp.as_bytes()),
+                hex_bytes(row.record.as_bytes()),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "provenance")]
+pub(crate) fn read_provenances<R: BufRead>(
+    reader: R,
+) -> Result<crate::provenance::OwningProvenanceTable, TskitError> {
+    let mut table = crate::provenance::OwningProvenanceTable::default();
+    let mut reader = Reader::new(reader, PROVENANCE_COLUMNS)?;
+    while let Some(record) = reader.next_record()? {
+        // The provenance table only gives access to text
+        let text = |column| {
+            let bytes = record.bytes(column)?;
+            String::from_utf8(bytes).map_err(|_| record.invalid(column, "hexadecimal UTF-8 text"))
+        };
+        let (timestamp, provenance) = (text(1)?, text(2)?);
+        let (timestamp, timestamp_length) = as_raw_parts(timestamp.as_bytes());
+        let (provenance, provenance_length) = as_raw_parts(provenance.as_bytes());
+        // SAFETY: the table pointer is not null
+        check_row(unsafe {
+            ll_bindings::tsk_provenance_table_add_row(
+                table.as_mut_ptr(),
+                timestamp,
+                timestamp_length,
+                provenance,
+                provenance_length,
+            )
+        })?;
+    }
+    Ok(table)
+}
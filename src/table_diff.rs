@@ -0,0 +1,270 @@
+use crate::sys::bindings as ll_bindings;
+use crate::sys::bindings::tsk_id_t;
+use crate::sys::bindings::tsk_size_t;
+use crate::TableCollection;
+use crate::TableEqualityOptions;
+use crate::TskitError;
+use crate::{EdgeId, IndividualId, MigrationId, MutationId, NodeId, PopulationId, SiteId};
+
+/// Summary of the differences between two
+/// [`TableCollection`] objects.
+///
+/// Created by [`TableCollection::diff`].
+///
+/// For each table, the field is `None` if the tables are equal.
+/// Otherwise, the field contains the first row
+/// that differs between the two tables.
+/// If one table is a prefix of the other, the first
+/// differing row is the first row not present in the shorter table.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TableDiff {
+    /// `true` if the sequence lengths differ.
+    pub sequence_length: bool,
+    /// `true` if the time units differ.
+    pub time_units: bool,
+    /// `true` if the metadata of the table collection,
+    /// or its schema, differ.
+    pub metadata: bool,
+    /// `true` if the metadata schema of any table differs.
+    pub metadata_schemas: bool,
+    /// `true` if the reference sequences differ.
+    pub reference_sequence: bool,
+    /// First differing row of the edge tables.
+    pub edges: Option<EdgeId>,
+    /// First differing row of the node tables.
+    pub nodes: Option<NodeId>,
+    /// First differing row of the site tables.
+    pub sites: Option<SiteId>,
+    /// First differing row of the mutation tables.
+    pub mutations: Option<MutationId>,
+    /// First differing row of the individual tables.
+    pub individuals: Option<IndividualId>,
+    /// First differing row of the population tables.
+    pub populations: Option<PopulationId>,
+    /// First differing row of the migration tables.
+    pub migrations: Option<MigrationId>,
+    #[cfg(feature = "provenance")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "provenance")))]
+    /// First differing row of the provenance tables.
+    pub provenances: Option<crate::ProvenanceId>,
+}
+
+/// How close two floating point values must be to compare equal.
+///
+/// Used by [`TableCollection::approx_equals`].
+///
+/// Values that are exactly equal, including infinite values
+/// and identical `NaN` values, are always close.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatTolerance {
+    /// `a` and `b` are close if `|a - b| <= tolerance`.
+    Absolute(f64),
+    /// `a` and `b` are close if `|a - b| <= tolerance * max(|a|, |b|)`.
+    Relative(f64),
+}
+
+impl FloatTolerance {
+    fn is_close(&self, a: f64, b: f64) -> bool {
+        if a == b || a.to_bits() == b.to_bits() {
+            return true;
+        }
+        let difference = (a - b).abs();
+        match *self {
+            Self::Absolute(tolerance) => difference <= tolerance,
+            Self::Relative(tolerance) => difference <= tolerance * a.abs().max(b.abs()),
+        }
+    }
+
+    // Replace each value of `other` that is close to
+    // the corresponding value of `values`.
+    fn snap(&self, values: &[f64], other: &mut [f64]) {
+        if values.len() == other.len() {
+            for (x, y) in values.iter().zip(other.iter_mut()) {
+                if self.is_close(*x, *y) {
+                    *y = *x;
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn approx_equals(
+    tables: &TableCollection,
+    other: &TableCollection,
+    options: TableEqualityOptions,
+    tolerance: FloatTolerance,
+) -> Result<bool, TskitError> {
+    let mut other = other.deepcopy()?;
+    let ptr = other.as_mut_ptr();
+    // SAFETY: the pointer is not null and the column
+    // lengths are the numbers of rows
+    unsafe {
+        let length = f64::from(tables.sequence_length());
+        if tolerance.is_close(length, (*ptr).sequence_length) {
+            (*ptr).sequence_length = length;
+        }
+        let edges = &mut (*ptr).edges;
+        tolerance.snap(
+            tables.edges().left_slice_raw(),
+            crate::sys::generate_slice_mut(edges.left, edges.num_rows),
+        );
+        tolerance.snap(
+            tables.edges().right_slice_raw(),
+            crate::sys::generate_slice_mut(edges.right, edges.num_rows),
+        );
+        let nodes = &mut (*ptr).nodes;
+        tolerance.snap(
+            tables.nodes().time_slice_raw(),
+            crate::sys::generate_slice_mut(nodes.time, nodes.num_rows),
+        );
+        let sites = &mut (*ptr).sites;
+        tolerance.snap(
+            tables.sites().position_slice_raw(),
+            crate::sys::generate_slice_mut(sites.position, sites.num_rows),
+        );
+        let mutations = &mut (*ptr).mutations;
+        tolerance.snap(
+            tables.mutations().time_slice_raw(),
+            crate::sys::generate_slice_mut(mutations.time, mutations.num_rows),
+        );
+        let migrations = &mut (*ptr).migrations;
+        tolerance.snap(
+            tables.migrations().left_slice_raw(),
+            crate::sys::generate_slice_mut(migrations.left, migrations.num_rows),
+        );
+        tolerance.snap(
+            tables.migrations().right_slice_raw(),
+            crate::sys::generate_slice_mut(migrations.right, migrations.num_rows),
+        );
+        tolerance.snap(
+            tables.migrations().time_slice_raw(),
+            crate::sys::generate_slice_mut(migrations.time, migrations.num_rows),
+        );
+    }
+    Ok(tables.equals(&other, options))
+}
+
+fn text<'a>(data: *const libc::c_char, length: tsk_size_t) -> &'a [u8] {
+    match length {
+        0 => &[],
+        n => crate::sys::generate_slice(data, n),
+    }
+}
+
+fn first_differing_row<T: PartialEq>(
+    mut a: impl Iterator<Item = T>,
+    mut b: impl Iterator<Item = T>,
+) -> Option<tsk_id_t> {
+    let mut row: tsk_id_t = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return None,
+            (Some(x), Some(y)) if x == y => row += 1,
+            _ => return Some(row),
+        }
+    }
+}
+
+macro_rules! diff_table_rows {
+    ($a: expr, $b: expr, $iter: ident, $ignore_metadata: expr) => {{
+        let strip = |mut row: crate::$iter| {
+            if $ignore_metadata {
+                row.metadata = None;
+            }
+            row
+        };
+        first_differing_row($a.iter().map(strip), $b.iter().map(strip)).map(|row| row.into())
+    }};
+}
+
+impl TableDiff {
+    pub(crate) fn new(
+        tables: &TableCollection,
+        other: &TableCollection,
+        options: TableEqualityOptions,
+    ) -> Self {
+        let ignore_metadata = options.contains(TableEqualityOptions::IGNORE_METADATA);
+        // IGNORE_METADATA implies IGNORE_TS_METADATA, as in tskit-c
+        let ignore_ts_metadata =
+            ignore_metadata || options.contains(TableEqualityOptions::IGNORE_TS_METADATA);
+        // SAFETY: the pointers are not null
+        let (a, b) = unsafe { (&*tables.as_ptr(), &*other.as_ptr()) };
+        Self {
+            sequence_length: !crate::util::partial_cmp_equal(
+                &tables.sequence_length(),
+                &other.sequence_length(),
+            ),
+            time_units: text(a.time_units, a.time_units_length)
+                != text(b.time_units, b.time_units_length),
+            metadata: !ignore_ts_metadata
+                && (text(a.metadata, a.metadata_length) != text(b.metadata, b.metadata_length)
+                    || text(a.metadata_schema, a.metadata_schema_length)
+                        != text(b.metadata_schema, b.metadata_schema_length)),
+            metadata_schemas: !ignore_metadata
+                && tables.metadata_schemas() != other.metadata_schemas(),
+            // SAFETY: the reference sequences belong to valid tables
+            reference_sequence: !unsafe {
+                ll_bindings::tsk_reference_sequence_equals(
+                    &a.reference_sequence,
+                    &b.reference_sequence,
+                    options.bits(),
+                )
+            },
+            edges: diff_table_rows!(tables.edges(), other.edges(), EdgeTableRow, ignore_metadata),
+            nodes: diff_table_rows!(tables.nodes(), other.nodes(), NodeTableRow, ignore_metadata),
+            sites: diff_table_rows!(tables.sites(), other.sites(), SiteTableRow, ignore_metadata),
+            mutations: diff_table_rows!(
+                tables.mutations(),
+                other.mutations(),
+                MutationTableRow,
+                ignore_metadata
+            ),
+            individuals: diff_table_rows!(
+                tables.individuals(),
+                other.individuals(),
+                IndividualTableRow,
+                ignore_metadata
+            ),
+            populations: diff_table_rows!(
+                tables.populations(),
+                other.populations(),
+                PopulationTableRow,
+                ignore_metadata
+            ),
+            migrations: diff_table_rows!(
+                tables.migrations(),
+                other.migrations(),
+                MigrationTableRow,
+                ignore_metadata
+            ),
+            #[cfg(feature = "provenance")]
+            provenances: match options.contains(TableEqualityOptions::IGNORE_PROVENANCE) {
+                true => None,
+                false => {
+                    let ignore_timestamps =
+                        options.contains(TableEqualityOptions::IGNORE_TIMESTAMPS);
+                    let strip = |mut row: crate::provenance::ProvenanceTableRow| {
+                        if ignore_timestamps {
+                            row.timestamp.clear();
+                        }
+                        row
+                    };
+                    first_differing_row(
+                        tables.provenances().iter().map(strip),
+                        other.provenances().iter().map(strip),
+                    )
+                    .map(|row| row.into())
+                }
+            },
+        }
+    }
+
+    /// Return `true` if no differences were found.
+    ///
+    /// Without the `provenance` feature, provenance tables
+    /// are not compared.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
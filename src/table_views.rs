@@ -90,6 +90,11 @@ impl TableViews {
         &self.edges
     }
 
+    /// Get mutable reference to the [``EdgeTable``](crate::EdgeTable).
+    pub fn edges_mut(&mut self) -> &mut EdgeTable {
+        &mut self.edges
+    }
+
     /// Get reference to the [``NodeTable``](crate::NodeTable).
     pub fn nodes(&self) -> &NodeTable {
         &self.nodes
@@ -105,11 +110,21 @@ impl TableViews {
         &self.sites
     }
 
+    /// Get mutable reference to the [``SiteTable``](crate::SiteTable).
+    pub fn sites_mut(&mut self) -> &mut SiteTable {
+        &mut self.sites
+    }
+
     /// Get reference to the [``MutationTable``](crate::MutationTable).
     pub fn mutations(&self) -> &MutationTable {
         &self.mutations
     }
 
+    /// Get mutable reference to the [``MutationTable``](crate::MutationTable).
+    pub fn mutations_mut(&mut self) -> &mut MutationTable {
+        &mut self.mutations
+    }
+
     /// Get reference to the [``IndividualTable``](crate::IndividualTable).
     pub fn individuals(&self) -> &IndividualTable {
         &self.individuals
@@ -1,4 +1,5 @@
 use crate::sys;
+use crate::EdgeId;
 use crate::NodeId;
 use crate::Position;
 use crate::SizeType;
@@ -302,6 +303,60 @@ impl TreeInterface {
         sys::generate_slice(self.as_ref().right_child, self.array_len)
     }
 
+    /// Return a copy of [`TreeInterface::parent_array`].
+    ///
+    /// The returned value is an independent copy of the data
+    /// and is therefore not tied to the lifetime of the tree.
+    /// Prefer the borrowing version when looping over
+    /// many trees, as each call allocates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::new(1.).unwrap();
+    /// let treeseq =
+    /// tables.tree_sequence(tskit::TreeSequenceFlags::BUILD_INDEXES).unwrap();
+    /// let mut tree_iter = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let mut snapshots = vec![];
+    /// while let Some(tree) = tree_iter.next() {
+    ///     snapshots.push(tree.parent_vec());
+    /// }
+    /// drop(tree_iter);
+    /// assert_eq!(snapshots.len(), 1);
+    /// ```
+    pub fn parent_vec(&self) -> Vec<NodeId> {
+        self.parent_array().to_vec()
+    }
+
+    /// Return a copy of [`TreeInterface::left_sib_array`].
+    ///
+    /// See [`TreeInterface::parent_vec`] for details.
+    pub fn left_sib_vec(&self) -> Vec<NodeId> {
+        self.left_sib_array().to_vec()
+    }
+
+    /// Return a copy of [`TreeInterface::right_sib_array`].
+    ///
+    /// See [`TreeInterface::parent_vec`] for details.
+    pub fn right_sib_vec(&self) -> Vec<NodeId> {
+        self.right_sib_array().to_vec()
+    }
+
+    /// Return a copy of [`TreeInterface::left_child_array`].
+    ///
+    /// See [`TreeInterface::parent_vec`] for details.
+    pub fn left_child_vec(&self) -> Vec<NodeId> {
+        self.left_child_array().to_vec()
+    }
+
+    /// Return a copy of [`TreeInterface::right_child_array`].
+    ///
+    /// See [`TreeInterface::parent_vec`] for details.
+    pub fn right_child_vec(&self) -> Vec<NodeId> {
+        self.right_child_array().to_vec()
+    }
+
     // error if we are not tracking samples,
     // Ok(None) if u is out of range
     fn left_sample<N: Into<NodeId> + Copy>(&self, u: N) -> Option<NodeId> {
@@ -429,6 +484,32 @@ impl TreeInterface {
         ChildIterator::new(self, u.into())
     }
 
+    /// Return an [`Iterator`] over the edges from node `u` to its children.
+    ///
+    /// The edges are in the same order as the nodes returned by
+    /// [`TreeInterface::children`].
+    /// The iterator is empty if `u` is out of range.
+    /// The children of the [`virtual root`](TreeInterface::virtual_root)
+    /// are the roots, which have no edge, so [`EdgeId::NULL`] is returned for each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// for e in tree.child_edges(3) {
+    ///     assert_eq!(treeseq.edges().parent(e), Some(3.into()));
+    /// }
+    /// assert_eq!(tree.child_edges(3).count(), 2);
+    /// ```
+    pub fn child_edges<N: Into<NodeId> + Copy>(&self, u: N) -> impl Iterator<Item = EdgeId> + '_ {
+        let edges: &[EdgeId] = sys::generate_slice(self.as_ref().edge, self.array_len);
+        self.children(u).map(move |c| edges[c.as_usize()])
+    }
+
     /// Return an [`Iterator`] over the sample nodes descending from node `u`.
     ///
     /// # Note
@@ -468,6 +549,49 @@ impl TreeInterface {
         v
     }
 
+    /// Return an [`Iterator`] over the roots of the tree and
+    /// the number of samples in the subtree of each root.
+    ///
+    /// The roots are visited in the same order as [`TreeInterface::roots`].
+    ///
+    /// # Note
+    ///
+    /// If the tree was created with [`TreeFlags::NO_SAMPLE_COUNTS`],
+    /// each count is found by a traversal of the root's subtree.
+    /// That traversal allocates memory, and may therefore fail.
+    ///
+    /// # Errors
+    ///
+    /// Each item is a [`TskitError`] if the underlying C library
+    /// returns an error code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// for item in tree.roots_with_sample_counts() {
+    ///     let (root, num_samples) = item.unwrap();
+    ///     assert_eq!(root, tree.roots_to_vec()[0]);
+    ///     assert_eq!(num_samples, 3);
+    /// }
+    /// ```
+    pub fn roots_with_sample_counts(
+        &self,
+    ) -> impl Iterator<Item = Result<(NodeId, SizeType), TskitError>> + '_ {
+        self.roots().map(move |root| {
+            let mut num_samples: tsk_size_t = 0;
+            // SAFETY: our pointer is not null
+            let rv = unsafe {
+                ll_bindings::tsk_tree_get_num_samples(self.as_ptr(), root.into(), &mut num_samples)
+            };
+            handle_tsk_return_value!(rv, (root, num_samples.into()))
+        })
+    }
+
     /// Return an [`Iterator`] over all nodes in the tree.
     ///
     /// # Parameters
@@ -482,9 +606,44 @@ impl TreeInterface {
         match order {
             NodeTraversalOrder::Preorder => Box::new(PreorderNodeIterator::new(self)),
             NodeTraversalOrder::Postorder => Box::new(PostorderNodeIterator::new(self)),
+            NodeTraversalOrder::LevelOrder => Box::new(LevelOrderNodeIterator::new(self)),
+            NodeTraversalOrder::TimeAscending => {
+                Box::new(self.nodes_sorted_by_time(false).into_iter())
+            }
+            NodeTraversalOrder::TimeDescending => {
+                Box::new(self.nodes_sorted_by_time(true).into_iter())
+            }
         }
     }
 
+    // All nodes in the current tree, sorted by time.
+    // Ties are broken by node id.
+    fn nodes_sorted_by_time(&self, descending: bool) -> Vec<NodeId> {
+        let time = self.node_times();
+        let mut nodes = PreorderNodeIterator::new(self).collect::<Vec<_>>();
+        nodes.sort_unstable_by(|a, b| {
+            let ta = time[a.as_usize()];
+            let tb = time[b.as_usize()];
+            let order = match descending {
+                true => tb.partial_cmp(&ta),
+                false => ta.partial_cmp(&tb),
+            };
+            order.unwrap_or(std::cmp::Ordering::Equal).then(a.cmp(b))
+        });
+        nodes
+    }
+
+    fn node_times(&self) -> &[Time] {
+        sys::generate_slice(
+            unsafe {
+                (*(*(*self.non_owned_pointer.as_ptr()).tree_sequence).tables)
+                    .nodes
+                    .time
+            },
+            self.num_nodes,
+        )
+    }
+
     /// Return the [`crate::NodeTable`] for this current tree
     /// (and the tree sequence from which it came).
     ///
@@ -503,14 +662,7 @@ impl TreeInterface {
     ///
     /// [`TskitError`] may be returned if a node index is out of range.
     pub fn total_branch_length(&self, by_span: bool) -> Result<Time, TskitError> {
-        let time: &[Time] = sys::generate_slice(
-            unsafe {
-                (*(*(*self.non_owned_pointer.as_ptr()).tree_sequence).tables)
-                    .nodes
-                    .time
-            },
-            self.num_nodes,
-        );
+        let time = self.node_times();
         let mut b = Time::from(0.);
         for n in self.traverse_nodes(NodeTraversalOrder::Preorder) {
             let p = self.parent(n).ok_or(TskitError::IndexError {})?;
@@ -525,6 +677,110 @@ impl TreeInterface {
         }
     }
 
+    /// Calculate the total length of the tree using the C library.
+    ///
+    /// The result is the same as that of
+    /// [`TreeInterface::total_branch_length`] with `by_span == false`,
+    /// but the traversal is done by `tsk_tree_get_total_branch_length`.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library returns an error code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// assert_eq!(tree.total_branch_length_c().unwrap(), 5.0);
+    /// ```
+    pub fn total_branch_length_c(&self) -> Result<Time, TskitError> {
+        let mut result = f64::NAN;
+        // SAFETY: our pointer is not null
+        let rv = unsafe {
+            ll_bindings::tsk_tree_get_total_branch_length(
+                self.as_ptr(),
+                ll_bindings::TSK_NULL,
+                &mut result,
+            )
+        };
+        handle_tsk_return_value!(rv, result.into())
+    }
+
+    /// Calculate the Sackin index of the tree.
+    ///
+    /// The Sackin index is the sum over all leaves
+    /// of the number of edges between the leaf and its root.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library returns an error code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// assert_eq!(tree.sackin_index().unwrap(), 5);
+    /// assert_eq!(tree.colless_index().unwrap(), 1);
+    /// ```
+    pub fn sackin_index(&self) -> Result<u64, TskitError> {
+        let mut result: tsk_size_t = 0;
+        // SAFETY: our pointer is not null
+        let rv = unsafe { ll_bindings::tsk_tree_sackin_index(self.as_ptr(), &mut result) };
+        handle_tsk_return_value!(rv, result)
+    }
+
+    /// Calculate the Colless index of the tree.
+    ///
+    /// The Colless index is the sum over all internal nodes
+    /// of the absolute difference between the number of leaves
+    /// below the left and right children.
+    ///
+    /// See [`TreeInterface::sackin_index`] for an example.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the tree has more than one root or if
+    /// any node has other than zero or two children.
+    pub fn colless_index(&self) -> Result<u64, TskitError> {
+        let mut result: tsk_size_t = 0;
+        // SAFETY: our pointer is not null
+        let rv = unsafe { ll_bindings::tsk_tree_colless_index(self.as_ptr(), &mut result) };
+        handle_tsk_return_value!(rv, result)
+    }
+
+    /// Calculate the B1 balance index of the tree.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library returns an error code.
+    pub fn b1_index(&self) -> Result<f64, TskitError> {
+        let mut result = f64::NAN;
+        // SAFETY: our pointer is not null
+        let rv = unsafe { ll_bindings::tsk_tree_b1_index(self.as_ptr(), &mut result) };
+        handle_tsk_return_value!(rv, result)
+    }
+
+    /// Calculate the B2 balance index of the tree,
+    /// using logarithms to the given `base`.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the tree has more than one root.
+    pub fn b2_index(&self, base: f64) -> Result<f64, TskitError> {
+        let mut result = f64::NAN;
+        // SAFETY: our pointer is not null
+        let rv = unsafe { ll_bindings::tsk_tree_b2_index(self.as_ptr(), base, &mut result) };
+        handle_tsk_return_value!(rv, result)
+    }
+
     /// Get the number of samples below node `u`.
     ///
     /// # Errors
@@ -542,6 +798,80 @@ impl TreeInterface {
         handle_tsk_return_value!(code, n.into())
     }
 
+    /// Get the number of tracked samples in the tree.
+    ///
+    /// This is the number of tracked samples below the
+    /// [`virtual root`](TreeInterface::virtual_root).
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError`] if [`TreeFlags::NO_SAMPLE_COUNTS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// assert_eq!(tree.num_tracked_samples_total().unwrap(), treeseq.num_samples());
+    /// ```
+    pub fn num_tracked_samples_total(&self) -> Result<SizeType, TskitError> {
+        self.num_tracked_samples(self.virtual_root())
+    }
+
+    /// Get the number of samples below any of `nodes`.
+    ///
+    /// Each sample is counted once, so nodes that are descendants of
+    /// other nodes in `nodes` do not add to the total.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError`] if [`TreeFlags::NO_SAMPLE_COUNTS`].
+    /// * [`TskitError`] if any of `nodes` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// // Node 3 is the parent of nodes 0 and 1
+    /// let nodes = [0, 3, 2].map(tskit::NodeId::from);
+    /// assert_eq!(tree.num_tracked_samples_multi(&nodes).unwrap(), 3);
+    /// ```
+    pub fn num_tracked_samples_multi(&self, nodes: &[NodeId]) -> Result<SizeType, TskitError> {
+        let distinct = nodes
+            .iter()
+            .copied()
+            .collect::<std::collections::HashSet<_>>();
+        // The roots are not children of the virtual root
+        if distinct.contains(&self.virtual_root()) {
+            return self.num_tracked_samples(self.virtual_root());
+        }
+        let mut total: tsk_size_t = 0;
+        for &u in distinct.iter() {
+            let count = self.num_tracked_samples(u)?;
+            // Skip nodes below another node that is counted
+            let mut p = self.parent(u).unwrap_or(NodeId::NULL);
+            let mut is_covered = false;
+            while !p.is_null() {
+                if distinct.contains(&p) {
+                    is_covered = true;
+                    break;
+                }
+                p = self.parent(p).unwrap_or(NodeId::NULL);
+            }
+            if !is_covered {
+                total += tsk_size_t::from(count);
+            }
+        }
+        Ok(total.into())
+    }
+
     /// Calculate the average Kendall-Colijn (`K-C`) distance between
     /// pairs of trees whose intervals overlap.
     ///
@@ -563,10 +893,507 @@ impl TreeInterface {
         handle_tsk_return_value!(code, kc)
     }
 
+    /// Return the root threshold.
+    ///
+    /// See [`Tree::set_root_threshold`](crate::Tree::set_root_threshold).
+    pub fn root_threshold(&self) -> SizeType {
+        // SAFETY: our pointer is not null
+        unsafe { ll_bindings::tsk_tree_get_root_threshold(self.as_ptr()) }.into()
+    }
+
+    /// Return the number of roots.
+    ///
+    /// See [`TreeInterface::roots`].
+    pub fn num_roots(&self) -> SizeType {
+        // SAFETY: our pointer is not null
+        unsafe { ll_bindings::tsk_tree_get_num_roots(self.as_ptr()) }.into()
+    }
+
+    /// Return the number of nodes in the tree.
+    ///
+    /// These are the nodes reachable from the [`roots`](TreeInterface::roots),
+    /// including the roots themselves.
+    /// The virtual root is not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// assert_eq!(tree.num_nodes_in_tree(), 5);
+    /// ```
+    pub fn num_nodes_in_tree(&self) -> SizeType {
+        let count = self.traverse_nodes(NodeTraversalOrder::Preorder).count();
+        (count as tsk_size_t).into()
+    }
+
+    /// Return `true` if the tree has no edges.
+    ///
+    /// In an empty tree, every node is isolated.
+    pub fn is_empty(&self) -> bool {
+        self.as_ref().num_edges == 0
+    }
+
     /// Return the virtual root of the tree.
     pub fn virtual_root(&self) -> NodeId {
         self.as_ref().virtual_root.into()
     }
+
+    /// Return the number of sites in this tree's interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let mut tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let site = tables.add_site(0., Some(b"A")).unwrap();
+    /// tables.add_mutation(site, 0, -1, 0.5, Some(b"T")).unwrap();
+    /// tables.add_mutation(site, 1, -1, 0.5, Some(b"T")).unwrap();
+    /// tables.add_site(5., Some(b"A")).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// assert_eq!(tree.num_sites(), 2);
+    /// assert_eq!(tree.num_mutations(), 2);
+    /// ```
+    pub fn num_sites(&self) -> SizeType {
+        self.as_ref().sites_length.into()
+    }
+
+    /// Return the number of mutations at sites in this tree's interval.
+    ///
+    /// See [`TreeInterface::num_sites`] for an example.
+    pub fn num_mutations(&self) -> SizeType {
+        let tree = self.as_ref();
+        let sites: &[ll_bindings::tsk_site_t] = match tree.sites_length {
+            0 => &[],
+            n => sys::generate_slice(tree.sites, n),
+        };
+        sites
+            .iter()
+            .map(|site| site.mutations_length)
+            .sum::<tsk_size_t>()
+            .into()
+    }
+
+    /// Return `true` if node `u` is a sample.
+    ///
+    /// Returns `false` if `u` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// assert!(tree.is_sample(0));
+    /// assert!(!tree.is_sample(2));
+    /// assert!(!tree.is_sample(tree.virtual_root()));
+    /// ```
+    pub fn is_sample<N: Into<NodeId>>(&self, u: N) -> bool {
+        // SAFETY: self pointer is not null
+        unsafe { ll_bindings::tsk_tree_is_sample(self.as_ptr(), u.into().into()) }
+    }
+
+    /// Get the time of node `u`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(time)` if `u` is valid.
+    ///   The time of the [virtual root](TreeInterface::virtual_root)
+    ///   is infinite.
+    /// * `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// let root = tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// let leaf = tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_edge(0., 100., root, leaf).unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// assert_eq!(tree.time(leaf), Some(0.0.into()));
+    /// assert_eq!(tree.time(root), Some(1.0.into()));
+    /// assert_eq!(tree.time(tree.virtual_root()), Some(f64::INFINITY.into()));
+    /// assert!(tree.time(tskit::NodeId::NULL).is_none());
+    /// ```
+    pub fn time<N: Into<NodeId>>(&self, u: N) -> Option<Time> {
+        let u = u.into();
+        let mut time = f64::NAN;
+        // SAFETY: our pointer is not null
+        let rv = unsafe { ll_bindings::tsk_tree_get_time(self.as_ptr(), u.into(), &mut time) };
+        match rv {
+            0 => Some(time.into()),
+            _ => None,
+        }
+    }
+
+    /// Return the time from node `u` to the root of its subtree.
+    ///
+    /// This is the time of the root minus the time of `u`.
+    /// Unlike the number of edges to the root, this value differs between
+    /// sample nodes for trees that are not ultrametric.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(time)` if `u` is valid. The time is zero if `u` is a root.
+    /// * `None` if `u` is out of range or is the
+    ///   [virtual root](TreeInterface::virtual_root).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:1);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// let root = tree.roots_to_vec()[0];
+    /// assert_eq!(tree.time_to_root(0), tree.time(root));
+    /// // The tree is not ultrametric
+    /// assert_eq!(tree.time_to_root(2), Some(1.0.into()));
+    /// assert_eq!(tree.time_to_root(root), Some(0.0.into()));
+    /// assert!(tree.time_to_root(tree.virtual_root()).is_none());
+    /// assert!(tree.time_to_root(tskit::NodeId::NULL).is_none());
+    /// ```
+    pub fn time_to_root<N: Into<NodeId>>(&self, u: N) -> Option<Time> {
+        let u = u.into();
+        if u == self.virtual_root() {
+            return None;
+        }
+        let time = self.time(u)?;
+        let mut root = u;
+        while let Some(p) = self.parent(root).filter(|p| !p.is_null()) {
+            root = p;
+        }
+        Some(self.time(root)? - time)
+    }
+
+    /// Return the ancestor of node `u` that is present at `time`.
+    ///
+    /// This is the node at the bottom of the branch above `u`
+    /// that spans `time`, found by moving up the tree from `u`
+    /// until the parent is older than `time`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(u)` if `time` is less than or equal to the time of `u`.
+    /// * `Some(root)` if `time` is at least the time of the root of the
+    ///   subtree containing `u`.
+    /// * `None` if `u` is out of range or is the
+    ///   [virtual root](TreeInterface::virtual_root).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// let parent = tree.parent(0).unwrap();
+    /// let root = tree.roots_to_vec()[0];
+    /// assert_eq!(tree.ancestor_at_time(0, 0.5.into()), Some(0.into()));
+    /// assert_eq!(tree.ancestor_at_time(0, 1.5.into()), Some(parent));
+    /// assert_eq!(tree.ancestor_at_time(2, 1.5.into()), Some(2.into()));
+    /// assert_eq!(tree.ancestor_at_time(0, 100.0.into()), Some(root));
+    /// assert!(tree.ancestor_at_time(tskit::NodeId::NULL, 0.0.into()).is_none());
+    /// ```
+    pub fn ancestor_at_time<N: Into<NodeId>>(&self, u: N, time: Time) -> Option<NodeId> {
+        let u = u.into();
+        if u == self.virtual_root() {
+            return None;
+        }
+        self.time(u)?;
+        let node_times = self.node_times();
+        let mut ancestor = u;
+        while let Some(p) = self.parent(ancestor).filter(|p| !p.is_null()) {
+            if node_times[p.as_usize()] > time {
+                break;
+            }
+            ancestor = p;
+        }
+        Some(ancestor)
+    }
+
+    /// Return the most recent common ancestor of nodes `u` and `v`.
+    ///
+    /// # Returns
+    ///
+    /// * The MRCA of `u` and `v`, which is `u` if `u == v`.
+    /// * [`NodeId::NULL`] if `u` and `v` are in different subtrees.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if `u` or `v` are out of range.
+    pub fn mrca<U: Into<NodeId>, V: Into<NodeId>>(&self, u: U, v: V) -> Result<NodeId, TskitError> {
+        let mut mrca: tsk_id_t = NodeId::NULL.into();
+        // SAFETY: our pointer is not null
+        let rv = unsafe {
+            ll_bindings::tsk_tree_get_mrca(
+                self.as_ptr(),
+                u.into().into(),
+                v.into().into(),
+                &mut mrca,
+            )
+        };
+        handle_tsk_return_value!(rv, mrca.into())
+    }
+
+    /// Return the times of the most recent common ancestor
+    /// of each pair of `samples`.
+    ///
+    /// Entry `[i][j]` is the time of the MRCA of `samples[i]` and `samples[j]`.
+    /// Diagonal entries are the times of the samples.
+    /// The time for a pair of nodes in different subtrees is infinite.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if any node in `samples` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("(n0:2.0,(n1:1.0,n2:1.0):1.0);", 100.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// let tmrca = tree.tmrca_matrix(treeseq.sample_nodes()).unwrap();
+    /// assert_eq!(tmrca[0][0], 0.0);
+    /// assert_eq!(tmrca[0][1], 2.0);
+    /// assert_eq!(tmrca[1][2], 1.0);
+    /// assert_eq!(tmrca[2][1], 1.0);
+    /// ```
+    pub fn tmrca_matrix(&self, samples: &[NodeId]) -> Result<Vec<Vec<Time>>, TskitError> {
+        let mut matrix = vec![vec![Time::from(f64::NAN); samples.len()]; samples.len()];
+        for (i, &u) in samples.iter().enumerate() {
+            matrix[i][i] = self.time(u).ok_or(TskitError::IndexError)?;
+            for (j, &v) in samples.iter().enumerate().skip(i + 1) {
+                let mrca = self.mrca(u, v)?;
+                let time = match mrca.is_null() {
+                    true => f64::INFINITY.into(),
+                    false => self.time(mrca).ok_or(TskitError::IndexError)?,
+                };
+                matrix[i][j] = time;
+                matrix[j][i] = time;
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Write the subtree below `root` in Newick format.
+    ///
+    /// Sample nodes are labelled `n<id>`, where `<id>` is the node id.
+    /// Other nodes are unlabelled.
+    ///
+    /// # Parameters
+    ///
+    /// * `root`: the root of the subtree to write.
+    /// * `precision`: the number of decimal places used for branch lengths.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if `root` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let newick = "(n0:2.0,(n1:1.0,n2:1.0):1.0);";
+    /// let tables = tskit::TableCollection::from_newick(newick, 100.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// let root = tree.roots_to_vec()[0];
+    /// assert_eq!(tree.to_newick(root, 1).unwrap(), newick);
+    /// ```
+    pub fn to_newick<N: Into<NodeId>>(
+        &self,
+        root: N,
+        precision: u32,
+    ) -> Result<String, TskitError> {
+        let root = root.into();
+        let mut buffer: Vec<std::os::raw::c_char> = vec![0; 1024];
+        loop {
+            // SAFETY: our pointer is not null and the buffer length is correct
+            let rv = unsafe {
+                ll_bindings::tsk_convert_newick(
+                    self.as_ptr(),
+                    root.into(),
+                    precision,
+                    0,
+                    buffer.len(),
+                    buffer.as_mut_ptr(),
+                )
+            };
+            if rv == ll_bindings::TSK_ERR_BUFFER_OVERFLOW {
+                buffer.resize(2 * buffer.len(), 0);
+                continue;
+            }
+            if rv < 0 {
+                return Err(TskitError::ErrorCode { code: rv });
+            }
+            // SAFETY: on success, the buffer contains a null-terminated string
+            let newick = unsafe { std::ffi::CStr::from_ptr(buffer.as_ptr()) };
+            return Ok(newick.to_string_lossy().into_owned());
+        }
+    }
+
+    /// Render the tree as text.
+    ///
+    /// Each node is written on its own line, labelled by its id
+    /// and indented below its parent.
+    /// The subtree below each root is written in turn.
+    /// Roots without children are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 100.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// let text = tree.draw_text().unwrap();
+    /// println!("{}", text);
+    /// assert_eq!(
+    ///     text,
+    ///     "4\n\
+    ///      +-- 2\n\
+    ///      `-- 3\n    \
+    ///          +-- 0\n    \
+    ///          `-- 1\n"
+    /// );
+    /// ```
+    pub fn draw_text(&self) -> Result<String, TskitError> {
+        let mut text = String::new();
+        for root in self
+            .roots()
+            .filter(|&r| self.left_child(r) != Some(NodeId::NULL))
+        {
+            text.push_str(&format!("{}\n", root));
+            let mut stack = vec![];
+            let push_children =
+                |stack: &mut Vec<(NodeId, String, bool)>, u: NodeId, prefix: &str| {
+                    let first = stack.len();
+                    stack.extend(self.children(u).map(|c| (c, prefix.to_string(), false)));
+                    if let Some(last) = stack[first..].last_mut() {
+                        last.2 = true;
+                    }
+                    stack[first..].reverse();
+                };
+            push_children(&mut stack, root, "");
+            while let Some((u, prefix, is_last)) = stack.pop() {
+                let (branch, indent) = match is_last {
+                    true => ("`-- ", "    "),
+                    false => ("+-- ", "|   "),
+                };
+                text.push_str(&format!("{}{}{}\n", prefix, branch, u));
+                push_children(&mut stack, u, &format!("{}{}", prefix, indent));
+            }
+        }
+        Ok(text)
+    }
+
+    /// Count the number of samples carrying each allele at a site.
+    ///
+    /// The keys of the returned map are the ancestral state
+    /// and the derived states of all mutations at the site.
+    /// Missing states are represented by empty vectors.
+    /// Alleles not carried by any sample have a count of zero.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::NotTrackingSamples`] if [`TreeFlags::SAMPLE_LISTS`] is not set.
+    /// * [`TskitError::ValueError`] if `site` is not within this tree's interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    /// let mut tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 100.).unwrap();
+    /// let site = tables.add_site(50., Some(b"A")).unwrap();
+    /// // Node 3 is the parent of nodes 0 and 1
+    /// tables.add_mutation(site, 3, tskit::MutationId::NULL, 1.5, Some(b"G")).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::SAMPLE_LISTS).unwrap();
+    /// let tree = trees.next().unwrap();
+    /// let counts = tree.allele_counts(site).unwrap();
+    /// assert_eq!(counts[&b"A".to_vec()], 1);
+    /// assert_eq!(counts[&b"G".to_vec()], 2);
+    /// ```
+    pub fn allele_counts<S: Into<crate::SiteId>>(
+        &self,
+        site: S,
+    ) -> Result<std::collections::HashMap<Vec<u8>, usize>, TskitError> {
+        let (states, sample_state) = self.site_states(site.into())?;
+        let mut counts = std::collections::HashMap::new();
+        for state in &states {
+            counts.entry(state.clone()).or_insert(0);
+        }
+        for s in self.samples_array()? {
+            *counts
+                .entry(states[sample_state[s.as_usize()]].clone())
+                .or_insert(0) += 1;
+        }
+        Ok(counts)
+    }
+
+    // Return the states at a site, starting with the ancestral state,
+    // and, for each node, the index of the state that it carries.
+    // Only the entries for samples are meaningful.
+    pub(crate) fn site_states(
+        &self,
+        site: crate::SiteId,
+    ) -> Result<(Vec<Vec<u8>>, Vec<usize>), TskitError> {
+        err_if_not_tracking_samples!(self.flags, ())?;
+        let tree = self.as_ref();
+        let sites: &[ll_bindings::tsk_site_t] = match tree.sites_length {
+            0 => &[],
+            n => sys::generate_slice(tree.sites, n),
+        };
+        let site = sites
+            .iter()
+            .find(|s| s.id == site)
+            .ok_or_else(|| TskitError::ValueError {
+                got: site.to_string(),
+                expected: "a site within the tree's interval".to_string(),
+            })?;
+        let to_state = |state: *const std::os::raw::c_char, length: tsk_size_t| match length {
+            0 => vec![],
+            n => sys::generate_slice::<_, _, u8>(state, n).to_vec(),
+        };
+        let mutations: &[ll_bindings::tsk_mutation_t] = match site.mutations_length {
+            0 => &[],
+            n => sys::generate_slice(site.mutations, n),
+        };
+
+        // The ancestral state is index 0.
+        // Mutations are ordered so that parents come before
+        // children, so later mutations overwrite earlier ones.
+        let mut states = vec![to_state(site.ancestral_state, site.ancestral_state_length)];
+        let mut node_state = vec![0_usize; self.array_len as usize];
+        for (i, mutation) in mutations.iter().enumerate() {
+            states.push(to_state(
+                mutation.derived_state,
+                mutation.derived_state_length,
+            ));
+            for s in self.samples(mutation.node)? {
+                node_state[s.as_usize()] = i + 1;
+            }
+        }
+        Ok((states, node_state))
+    }
 }
 
 /// Specify the traversal order used by
@@ -581,6 +1408,16 @@ pub enum NodeTraversalOrder {
     ///For trees with multiple roots, start at the left root,
     ///traverse to tips, proceeed to the next root, etc..
     Postorder,
+    ///Level order (breadth-first) traversal, starting at the root(s) of a [`TreeInterface`].
+    ///All nodes at a given depth are visited before any node at a greater depth.
+    ///Within a level, nodes are visited from left to right.
+    LevelOrder,
+    ///Visit the nodes of the current tree in order of increasing node time.
+    ///Nodes with equal times are visited in order of increasing node id.
+    TimeAscending,
+    ///Visit the nodes of the current tree in order of decreasing node time.
+    ///Nodes with equal times are visited in order of increasing node id.
+    TimeDescending,
 }
 
 // Trait defining iteration over nodes.
@@ -700,6 +1537,38 @@ impl<'a> Iterator for PostorderNodeIterator<'a> {
     }
 }
 
+struct LevelOrderNodeIterator<'a> {
+    node_queue: std::collections::VecDeque<NodeId>,
+    tree: &'a TreeInterface,
+    current_node_: Option<NodeId>,
+}
+
+impl<'a> LevelOrderNodeIterator<'a> {
+    fn new(tree: &'a TreeInterface) -> Self {
+        let node_queue = tree.roots().collect::<std::collections::VecDeque<_>>();
+        Self {
+            node_queue,
+            tree,
+            current_node_: None,
+        }
+    }
+}
+
+impl NodeIterator for LevelOrderNodeIterator<'_> {
+    fn next_node(&mut self) {
+        self.current_node_ = self.node_queue.pop_front();
+        if let Some(u) = self.current_node_ {
+            self.node_queue.extend(self.tree.children(u));
+        }
+    }
+
+    fn current_node(&mut self) -> Option<NodeId> {
+        self.current_node_
+    }
+}
+
+iterator_for_nodeiterator!(LevelOrderNodeIterator<'_>);
+
 struct RootIterator<'a> {
     current_root: Option<NodeId>,
     next_root: NodeId,
@@ -1,5 +1,9 @@
+mod node_id_map;
+mod summary;
 mod tree;
 mod treeseq;
 
+pub use node_id_map::NodeIdMap;
+pub use summary::TreeSequenceSummary;
 pub use tree::Tree;
 pub use treeseq::TreeSequence;
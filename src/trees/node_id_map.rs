@@ -0,0 +1,94 @@
+use crate::NodeId;
+
+/// Mapping from input node ids to output node ids
+/// resulting from simplification.
+///
+/// Created by [`TreeSequence::simplify_with_map`](crate::TreeSequence::simplify_with_map).
+///
+/// # Examples
+///
+/// ```
+/// let mut tables = tskit::TableCollection::new(1000.).unwrap();
+/// tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+/// tables.add_node(0, 2.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+/// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+/// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+/// tables.add_edge(0., 1000., 0, 2).unwrap();
+/// tables.add_edge(0., 1000., 0, 3).unwrap();
+/// tables.build_index().unwrap();
+/// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+///
+/// let (simplified, idmap) = treeseq
+///     .simplify_with_map(&[2.into(), 3.into()], tskit::SimplificationOptions::default())
+///     .unwrap();
+/// assert_eq!(simplified.nodes().num_rows(), 3);
+///
+/// // Samples come first in the output
+/// assert_eq!(idmap.new_id(2), Some(0.into()));
+/// assert_eq!(idmap.new_id(3), Some(1.into()));
+/// assert_eq!(idmap.new_id(0), Some(2.into()));
+/// // Node 1 is not ancestral to any sample
+/// assert!(idmap.new_id(1).is_none());
+/// // Out of range ids do not map to anything.
+/// assert!(idmap.new_id(100).is_none());
+/// assert!(idmap.new_id(tskit::NodeId::NULL).is_none());
+///
+/// let retained = idmap.old_ids_retained().collect::<Vec<_>>();
+/// assert_eq!(retained, vec![0, 2, 3]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeIdMap {
+    map: Vec<NodeId>,
+}
+
+impl NodeIdMap {
+    pub(crate) fn new(map: Vec<NodeId>) -> Self {
+        Self { map }
+    }
+
+    /// Get the output id of input node `old`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(NodeId)` if `old` is retained in the output.
+    /// * `None` if `old` is out of range or is not retained in the output.
+    pub fn new_id<N: Into<NodeId>>(&self, old: N) -> Option<NodeId> {
+        let old = old.into().to_usize()?;
+        self.map.get(old).copied().filter(|n| !n.is_null())
+    }
+
+    /// Iterate over all input node ids that are retained in the output.
+    /// The ids are visited in increasing order.
+    pub fn old_ids_retained(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.map
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| !n.is_null())
+            .map(|(i, _)| NodeId::from(i as crate::sys::bindings::tsk_id_t))
+    }
+
+    /// The number of entries in the map.
+    /// This value equals the number of rows in the input node table.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Return `true` if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Get the underlying map as a slice.
+    ///
+    /// Entry `i` is the output id of input node `i`,
+    /// or [`NodeId::NULL`] if that node is not retained.
+    pub fn as_slice(&self) -> &[NodeId] {
+        &self.map
+    }
+}
+
+impl From<NodeIdMap> for Vec<NodeId> {
+    fn from(value: NodeIdMap) -> Self {
+        value.map
+    }
+}
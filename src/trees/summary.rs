@@ -0,0 +1,55 @@
+use crate::Position;
+use crate::SizeType;
+
+/// Summary of the contents of a [`TreeSequence`](crate::TreeSequence).
+///
+/// Created by [`TreeSequence::summary`](crate::TreeSequence::summary).
+///
+/// The [`Display`](std::fmt::Display) implementation prints
+/// one field per line.
+///
+/// # Examples
+///
+/// ```
+/// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+/// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+/// let summary = treeseq.summary();
+/// assert_eq!(summary.num_trees, 1);
+/// assert_eq!(summary.num_samples, 3);
+/// assert_eq!(summary.sequence_length, 10.0);
+/// println!("{}", summary);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct TreeSequenceSummary {
+    /// The number of trees.
+    pub num_trees: SizeType,
+    /// The sequence length.
+    pub sequence_length: Position,
+    /// The number of sample nodes.
+    pub num_samples: SizeType,
+    /// The number of rows in the node table.
+    pub num_nodes: SizeType,
+    /// The number of rows in the edge table.
+    pub num_edges: SizeType,
+    /// The number of rows in the site table.
+    pub num_sites: SizeType,
+    /// The number of rows in the mutation table.
+    pub num_mutations: SizeType,
+    /// The number of rows in the population table.
+    pub num_populations: SizeType,
+}
+
+impl std::fmt::Display for TreeSequenceSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "TreeSequence")?;
+        writeln!(f, "  trees:           {}", self.num_trees)?;
+        writeln!(f, "  sequence length: {}", self.sequence_length)?;
+        writeln!(f, "  samples:         {}", self.num_samples)?;
+        writeln!(f, "  nodes:           {}", self.num_nodes)?;
+        writeln!(f, "  edges:           {}", self.num_edges)?;
+        writeln!(f, "  sites:           {}", self.num_sites)?;
+        writeln!(f, "  mutations:       {}", self.num_mutations)?;
+        write!(f, "  populations:     {}", self.num_populations)
+    }
+}
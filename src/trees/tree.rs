@@ -45,6 +45,115 @@ impl<'treeseq> Tree<'treeseq> {
             api,
         })
     }
+
+    /// Set the root threshold.
+    ///
+    /// A node is a root if it has at least `root_threshold`
+    /// samples below it.
+    /// The default value is `1`, meaning that isolated sample
+    /// nodes are roots.
+    /// Using a value of `2` means that isolated sample nodes
+    /// are *not* roots.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError`] if `root_threshold` is `0`.
+    /// * [`TskitError`] if the tree has already been advanced
+    ///   to a position on the genome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    ///
+    /// let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.build_index();
+    /// let tree_sequence = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut tree = tree_sequence.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// tree.set_root_threshold(2).unwrap();
+    /// assert_eq!(tree.root_threshold(), 2);
+    /// while let Some(tree) = tree.next() {
+    ///     // The isolated sample is not a root
+    ///     assert_eq!(tree.roots().count(), 0);
+    /// }
+    /// ```
+    ///
+    /// The threshold cannot be changed once iteration has started:
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    ///
+    /// let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// tables.build_index();
+    /// let tree_sequence = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut tree = tree_sequence.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// tree.next();
+    /// assert!(tree.set_root_threshold(2).is_err());
+    /// ```
+    pub fn set_root_threshold(
+        &mut self,
+        root_threshold: ll_bindings::tsk_size_t,
+    ) -> Result<(), TskitError> {
+        // SAFETY: our pointer is not null and
+        // is initialized b/c it comes from LLTree
+        let rv = unsafe {
+            ll_bindings::tsk_tree_set_root_threshold(self.inner.as_mut_ptr(), root_threshold)
+        };
+        handle_tsk_return_value!(rv, ())
+    }
+
+    /// Move to the first tree of the tree sequence.
+    ///
+    /// After seeking, [`StreamingIterator::get`](streaming_iterator::StreamingIterator::get)
+    /// returns the first tree and a subsequent call to
+    /// [`StreamingIterator::next`](streaming_iterator::StreamingIterator::next)
+    /// moves to the second tree.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library returns an error code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    ///
+    /// let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// tables.build_index();
+    /// let tree_sequence = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut tree = tree_sequence.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// tree.seek_first().unwrap();
+    /// assert_eq!(tree.interval(), (tskit::Position::from(0.0), tskit::Position::from(1000.0)));
+    /// assert!(tree.get().is_some());
+    /// // There is only one tree, so there is no next tree
+    /// assert!(tree.next().is_none());
+    /// ```
+    pub fn seek_first(&mut self) -> Result<(), TskitError> {
+        // SAFETY: our pointer is not null and
+        // is initialized b/c it comes from LLTree
+        let rv = unsafe { ll_bindings::tsk_tree_first(self.inner.as_mut_ptr()) };
+        self.advanced = rv == 1;
+        handle_tsk_return_value!(rv, ())
+    }
+
+    /// Move to the last tree of the tree sequence.
+    ///
+    /// After seeking, [`StreamingIterator::get`](streaming_iterator::StreamingIterator::get)
+    /// returns the last tree and a subsequent call to
+    /// [`DoubleEndedStreamingIterator::next_back`](streaming_iterator::DoubleEndedStreamingIterator::next_back)
+    /// moves to the next-to-last tree.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library returns an error code.
+    pub fn seek_last(&mut self) -> Result<(), TskitError> {
+        // SAFETY: our pointer is not null and
+        // is initialized b/c it comes from LLTree
+        let rv = unsafe { ll_bindings::tsk_tree_last(self.inner.as_mut_ptr()) };
+        self.advanced = rv == 1;
+        handle_tsk_return_value!(rv, ())
+    }
 }
 
 impl<'ts> streaming_iterator::StreamingIterator for Tree<'ts> {
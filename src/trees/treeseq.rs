@@ -1,6 +1,7 @@
 use crate::error::TskitError;
 use crate::sys;
 use crate::NodeId;
+use crate::Position;
 use crate::SimplificationOptions;
 use crate::SizeType;
 use crate::TableCollection;
@@ -9,6 +10,7 @@ use crate::TreeFlags;
 use crate::TreeSequenceFlags;
 use crate::TskReturnValue;
 use ll_bindings::tsk_id_t;
+use streaming_iterator::StreamingIterator;
 use sys::bindings as ll_bindings;
 
 use super::Tree;
@@ -39,6 +41,11 @@ use super::Tree;
 /// assert_eq!(treeseq.edges().num_rows(), 2);
 /// ```
 ///
+/// The table views are created once, along with the tree sequence.
+/// Accessors such as [`TreeSequence::edges`] return references to them
+/// and do not allocate, so `treeseq.edges().num_rows()` simply reads
+/// the row count of the underlying C table.
+///
 /// This type does not provide access to mutable tables.
 ///
 /// ```compile_fail
@@ -182,6 +189,118 @@ impl TreeSequence {
         handle_tsk_return_value!(rv, TableCollection::new_from_ll(inner)?)
     }
 
+    /// Convert into a [`TableCollection`] without copying the tables.
+    ///
+    /// This is the inverse of [`TableCollection::tree_sequence`]:
+    /// the tables are moved out of the tree sequence, which is consumed.
+    /// See [`TreeSequence::dump_tables`] to obtain a copy instead.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the table views cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let mut tables = treeseq.into_table_collection().unwrap();
+    /// tables.add_node(0, 2.0, -1, -1).unwrap();
+    /// assert_eq!(tables.nodes().num_rows(), 4);
+    /// ```
+    pub fn into_table_collection(self) -> Result<TableCollection, TskitError> {
+        let tables = self.inner.into_tables();
+        // SAFETY: the tree sequence owned the tables,
+        // which are always allocated with malloc.
+        let tables = unsafe { crate::sys::TableCollection::from_raw(tables) };
+        TableCollection::new_from_ll(tables)
+    }
+
+    /// Return `true` if `self` and `other` have the same tables,
+    /// ignoring all metadata, metadata schemas, and provenance.
+    ///
+    /// This is equivalent to calling
+    /// [`TableCollection::equals`] on the underlying tables with
+    /// [`TableEqualityOptions::IGNORE_METADATA`](crate::TableEqualityOptions::IGNORE_METADATA)
+    /// and
+    /// [`TableEqualityOptions::IGNORE_PROVENANCE`](crate::TableEqualityOptions::IGNORE_PROVENANCE).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let other = treeseq.deepcopy().unwrap();
+    /// assert!(treeseq.topologically_equal(&other));
+    /// ```
+    pub fn topologically_equal(&self, other: &TreeSequence) -> bool {
+        let options = crate::TableEqualityOptions::default()
+            .ignore_metadata()
+            .ignore_provenance();
+        // SAFETY: neither pointer is null
+        unsafe {
+            ll_bindings::tsk_table_collection_equals(
+                (*self.as_ptr()).tables,
+                (*other.as_ptr()).tables,
+                options.bits(),
+            )
+        }
+    }
+
+    /// Return a "deep" copy of the tree sequence.
+    ///
+    /// The copy is built from a copy of the tables,
+    /// and shares no data with `self`.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] will be raised if the underlying C library returns an error code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let copy = treeseq.deepcopy().unwrap();
+    /// assert_eq!(copy.nodes().num_rows(), treeseq.nodes().num_rows());
+    /// assert_ne!(copy.as_ptr(), treeseq.as_ptr());
+    /// ```
+    pub fn deepcopy(&self) -> Result<Self, TskitError> {
+        Self::new(self.dump_tables()?, TreeSequenceFlags::default())
+    }
+
+    /// Concatenate tree sequences along the genome.
+    ///
+    /// The coordinates of each tree sequence in `others` are shifted
+    /// by the total length of the tree sequences before it.
+    ///
+    /// Sample nodes keep their ids.
+    /// All other nodes of `others` are appended to the node table,
+    /// keeping their populations and individuals.
+    ///
+    /// Populations and individuals are not merged, so all tree
+    /// sequences must have the same population and individual tables.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::ValueError`] if the tree sequences do not have the same samples.
+    /// * [`TskitError::ValueError`] if the tree sequences do not have the same
+    ///   population and individual tables.
+    /// * [`TskitError`] if the resulting tables do not make a valid tree sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let concatenated = treeseq.concatenate(&[&treeseq]).unwrap();
+    /// assert_eq!(concatenated.num_trees(), 2);
+    /// assert_eq!(concatenated.nodes().num_rows(), 7);
+    /// ```
+    pub fn concatenate(&self, others: &[&TreeSequence]) -> Result<TreeSequence, TskitError> {
+        crate::concatenate::concatenate(self, others)
+    }
+
     /// Create an iterator over trees.
     ///
     /// # Parameters
@@ -243,6 +362,86 @@ impl TreeSequence {
         Ok(tree)
     }
 
+    /// Call `f` for each overlap of a tree with a window.
+    ///
+    /// `f` receives the tree, the index of the window, and
+    /// the span of the overlap between the tree's interval and the window.
+    /// Trees are visited from left to right, and a tree that overlaps
+    /// more than one window is passed to `f` once per window.
+    ///
+    /// # Parameters
+    ///
+    /// * `windows`: window boundaries. Window `i` is the interval
+    ///   `[windows[i], windows[i + 1])`.
+    /// * `flags`: see [`TreeSequence::tree_iterator`].
+    /// * `f`: the callback.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::ValueError`] if `windows` has fewer than two values,
+    ///   is not strictly increasing, or does not start at zero and end at
+    ///   the sequence length.
+    /// * [`TskitError`] if the tree iterator cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 100.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let windows = [0.0, 25.0, 100.0].map(tskit::Position::from);
+    /// let mut spans = vec![0.0; 2];
+    /// treeseq
+    ///     .for_each_tree_in_windows(&windows, tskit::TreeFlags::default(), |_, w, span| {
+    ///         spans[w] += f64::from(span)
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(spans, [25.0, 75.0]);
+    /// ```
+    pub fn for_each_tree_in_windows<FLAGS, F>(
+        &self,
+        windows: &[Position],
+        flags: FLAGS,
+        mut f: F,
+    ) -> Result<(), TskitError>
+    where
+        FLAGS: Into<TreeFlags>,
+        F: FnMut(&Tree, usize, Position),
+    {
+        // SAFETY: our pointer is not null
+        let sequence_length =
+            unsafe { ll_bindings::tsk_treeseq_get_sequence_length(self.as_ptr()) };
+        if windows.len() < 2
+            || windows[0] != 0.0
+            || windows[windows.len() - 1] != sequence_length
+            || windows.windows(2).any(|w| w[0] >= w[1])
+        {
+            return Err(TskitError::ValueError {
+                got: format!("{:?}", windows),
+                expected: format!("strictly increasing windows from 0 to {}", sequence_length),
+            });
+        }
+        let mut trees = self.tree_iterator(flags)?;
+        let mut first_window = 0;
+        while let Some(tree) = trees.next() {
+            let (left, right) = tree.interval();
+            while windows[first_window + 1] <= left {
+                first_window += 1;
+            }
+            let mut w = first_window;
+            while w + 1 < windows.len() && windows[w] < right {
+                let start = if windows[w] > left { windows[w] } else { left };
+                let stop = if windows[w + 1] < right {
+                    windows[w + 1]
+                } else {
+                    right
+                };
+                f(tree, w, stop - start);
+                w += 1;
+            }
+        }
+        Ok(())
+    }
+
     /// Get the list of samples as a vector.
     /// # Panics
     ///
@@ -271,14 +470,756 @@ impl TreeSequence {
         sys::generate_slice(self.as_ref().samples, num_samples)
     }
 
+    /// Get the sample nodes, sorted in increasing order of node id.
+    ///
+    /// The order of [`TreeSequence::sample_nodes`] is that of the
+    /// samples array of the C library, which is not documented.
+    /// This function guarantees that the order is that of the node table,
+    /// making it suitable for output that must be reproducible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,(n2:1,n3:1):1);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let samples = treeseq.samples_in_node_order();
+    /// assert_eq!(samples.len(), 4);
+    /// assert!(samples.windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    pub fn samples_in_node_order(&self) -> Vec<NodeId> {
+        let mut samples = self.sample_nodes().to_vec();
+        samples.sort_unstable();
+        samples
+    }
+
+    /// Get the sample nodes assigned to a given population.
+    ///
+    /// Passing [`PopulationId::NULL`](crate::PopulationId::NULL)
+    /// returns the samples not assigned to any population.
+    ///
+    /// The nodes are returned in the same order as
+    /// [`TreeSequence::sample_nodes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// let pop0 = tables.add_population().unwrap();
+    /// let pop1 = tables.add_population().unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, pop0, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, pop1, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, pop1, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// // Not a sample
+    /// tables.add_node(0, 1.0, pop0, tskit::IndividualId::NULL).unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// assert_eq!(treeseq.samples_in_population(pop0), vec![0]);
+    /// assert_eq!(treeseq.samples_in_population(pop1), vec![1, 2]);
+    /// assert_eq!(treeseq.samples_in_population(tskit::PopulationId::NULL), vec![3]);
+    /// ```
+    pub fn samples_in_population<P: Into<crate::PopulationId>>(&self, pop: P) -> Vec<NodeId> {
+        let pop = pop.into();
+        let population = self.nodes().population_slice();
+        self.sample_nodes()
+            .iter()
+            .filter(|u| population[u.as_usize()] == pop)
+            .copied()
+            .collect()
+    }
+
+    /// Get the number of sample nodes belonging to an individual.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ploidy)` if `ind` is a valid individual.
+    ///   Individuals without sample nodes have a ploidy of zero.
+    /// * `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// let ind0 = tables.add_individual(0, None, None).unwrap();
+    /// let ind1 = tables.add_individual(0, None, None).unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, ind0).unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, ind0).unwrap();
+    /// // Not a sample
+    /// tables.add_node(0, 1.0, tskit::PopulationId::NULL, ind1).unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// assert_eq!(treeseq.individual_ploidy(ind0), Some(2));
+    /// assert_eq!(treeseq.individual_ploidy(ind1), Some(0));
+    /// assert_eq!(treeseq.individual_ploidy(2), None);
+    /// ```
+    pub fn individual_ploidy<I: Into<crate::IndividualId>>(&self, ind: I) -> Option<usize> {
+        let ind = ind.into();
+        if ind.is_null() || ind.as_usize() >= self.individuals().num_rows().as_usize() {
+            return None;
+        }
+        let individual = self.nodes().individual_slice();
+        Some(
+            self.sample_nodes()
+                .iter()
+                .filter(|u| individual[u.as_usize()] == ind)
+                .count(),
+        )
+    }
+
+    /// Return an iterator over individuals and their sample nodes.
+    ///
+    /// Individuals are visited in the order of the individual table,
+    /// and individuals without sample nodes are skipped.
+    /// Within each individual, nodes are in the same order as
+    /// [`TreeSequence::sample_nodes`].
+    ///
+    /// Sample nodes without an individual are yielded last,
+    /// grouped under [`IndividualId::NULL`](crate::IndividualId::NULL).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// let ind0 = tables.add_individual(0, None, None).unwrap();
+    /// let ind1 = tables.add_individual(0, None, None).unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, ind0).unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, ind0).unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let groups = treeseq.samples_by_individual().collect::<Vec<_>>();
+    /// assert_eq!(groups.len(), 2);
+    /// assert_eq!(groups[0], (ind0, vec![0.into(), 2.into()]));
+    /// assert_eq!(groups[1], (tskit::IndividualId::NULL, vec![1.into()]));
+    /// ```
+    pub fn samples_by_individual(
+        &self,
+    ) -> impl Iterator<Item = (crate::IndividualId, Vec<NodeId>)> {
+        let individual = self.nodes().individual_slice();
+        let mut groups = vec![vec![]; self.individuals().num_rows().as_usize()];
+        let mut no_individual = vec![];
+        for &u in self.sample_nodes() {
+            let ind = individual[u.as_usize()];
+            match ind.is_null() {
+                true => no_individual.push(u),
+                false => groups[ind.as_usize()].push(u),
+            }
+        }
+        groups
+            .into_iter()
+            .enumerate()
+            .map(|(i, nodes)| (crate::IndividualId::from(i as tsk_id_t), nodes))
+            .chain(std::iter::once((crate::IndividualId::NULL, no_individual)))
+            .filter(|(_, nodes)| !nodes.is_empty())
+    }
+
+    /// Group sample nodes by a key computed from individual metadata.
+    ///
+    /// For each sample node, the metadata of its individual
+    /// are decoded as type `M` and passed to `key`.
+    /// Samples are grouped by the returned value.
+    ///
+    /// Samples with no individual, samples whose individual
+    /// has no metadata, and samples whose individual metadata
+    /// cannot be decoded as `M` are skipped.
+    /// Within each group, samples appear in the same order as
+    /// [`TreeSequence::sample_nodes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "derive")] {
+    /// #[derive(serde::Serialize, serde::Deserialize, tskit::metadata::IndividualMetadata)]
+    /// #[serializer("serde_json")]
+    /// struct IndividualMetadata {
+    ///    phenotype: i32,
+    /// }
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// for phenotype in [0, 1, 1] {
+    ///     let metadata = IndividualMetadata { phenotype };
+    ///     let individual = tables
+    ///         .add_individual_with_metadata(0, None, None, &metadata)
+    ///         .unwrap();
+    ///     tables
+    ///         .add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, individual)
+    ///         .unwrap();
+    /// }
+    /// // A sample without an individual
+    /// tables
+    ///     .add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+    ///     .unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let sample_sets = treeseq
+    ///     .sample_sets_by(|m: &IndividualMetadata| m.phenotype)
+    ///     .unwrap();
+    /// assert_eq!(sample_sets.len(), 2);
+    /// assert_eq!(sample_sets[&0], vec![0]);
+    /// assert_eq!(sample_sets[&1], vec![1, 2]);
+    /// # }
+    /// ```
+    pub fn sample_sets_by<M, K, F>(
+        &self,
+        key: F,
+    ) -> Result<std::collections::HashMap<K, Vec<NodeId>>, TskitError>
+    where
+        M: crate::metadata::IndividualMetadata,
+        K: Eq + std::hash::Hash,
+        F: Fn(&M) -> K,
+    {
+        let individual = self.nodes().individual_slice();
+        let mut sample_sets = std::collections::HashMap::<K, Vec<NodeId>>::new();
+        for &u in self.sample_nodes() {
+            let i = individual[u.as_usize()];
+            if i.is_null() {
+                continue;
+            }
+            if let Some(Ok(metadata)) = self.individuals().metadata::<M>(i) {
+                sample_sets.entry(key(&metadata)).or_default().push(u);
+            }
+        }
+        Ok(sample_sets)
+    }
+
+    /// Iterate over sites together with their mutations.
+    ///
+    /// Sites are visited in order of increasing position.
+    /// The mutations at each site are in table order,
+    /// meaning that parent mutations come before their children.
+    ///
+    /// # Note
+    ///
+    /// This function relies on the mutation table being sorted by site.
+    /// That requirement is checked when creating a tree sequence,
+    /// so it always holds here.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// let root = tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// let leaf = tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_edge(0., 100., root, leaf).unwrap();
+    /// tables.add_site(10., None).unwrap();
+    /// tables.add_site(20., None).unwrap();
+    /// tables.add_mutation(1, leaf, tskit::MutationId::NULL, 0.5, None).unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let sites = treeseq.sites_with_mutations().collect::<Vec<_>>();
+    /// assert_eq!(sites.len(), 2);
+    /// assert!(sites[0].1.is_empty());
+    /// assert_eq!(sites[1].1.len(), 1);
+    /// ```
+    pub fn sites_with_mutations(
+        &self,
+    ) -> impl Iterator<Item = (crate::SiteTableRow, Vec<crate::MutationTableRow>)> + '_ {
+        let mut mutations = self.mutations().iter().peekable();
+        self.sites().iter().map(move |site| {
+            let mut site_mutations = vec![];
+            while let Some(mutation) = mutations.next_if(|m| m.site == site.id) {
+                site_mutations.push(mutation);
+            }
+            (site, site_mutations)
+        })
+    }
+
+    /// Write the sample sequences in FASTA format.
+    ///
+    /// One record is written for each sample, in the order of
+    /// [`TreeSequence::sample_nodes`].
+    /// See [`FastaWriteOptions`](crate::FastaWriteOptions) for
+    /// controlling record names, line wrapping, and whether the
+    /// full sequence or only the sites are written.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::ValueError`] if any state is not exactly one character long.
+    /// * [`TskitError::ValueError`] if the full sequence is requested
+    ///   and the sequence length or any site position is not an integer.
+    /// * [`TskitError::ValueError`] if the number of names does not match
+    ///   the number of samples.
+    /// * [`TskitError::LibraryError`] if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 4.).unwrap();
+    /// let site = tables.add_site(2., Some(b"A")).unwrap();
+    /// tables.add_mutation(site, 0, tskit::MutationId::NULL, 0.5, Some(b"T")).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let options = tskit::FastaWriteOptions {
+    ///     reference_base: Some(b'N'),
+    ///     ..Default::default()
+    /// };
+    /// let mut fasta = vec![];
+    /// treeseq.write_fasta(&mut fasta, options).unwrap();
+    /// assert_eq!(fasta, b">n0\nNNTN\n>n1\nNNAN\n");
+    /// ```
+    pub fn write_fasta<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        options: crate::FastaWriteOptions,
+    ) -> Result<(), TskitError> {
+        crate::fasta::write_fasta(self, writer, options)
+    }
+
+    /// Write the sample sequences in FASTA format, naming
+    /// each record from the metadata of the sample's individual.
+    ///
+    /// Samples without an individual, or whose individual has no
+    /// metadata or metadata that cannot be decoded as `M`,
+    /// are named `n<node id>`, as in [`TreeSequence::sample_sets_by`].
+    /// The `names` field of `options` is ignored.
+    /// Otherwise, this function is the same as
+    /// [`TreeSequence::write_fasta`].
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by [`TreeSequence::write_fasta`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "derive")] {
+    /// #[derive(serde::Serialize, serde::Deserialize, tskit::metadata::IndividualMetadata)]
+    /// #[serializer("serde_json")]
+    /// struct IndividualMetadata {
+    ///    name: String,
+    /// }
+    /// let mut tables = tskit::TableCollection::new(1.).unwrap();
+    /// let metadata = IndividualMetadata { name: "sample_a".to_string() };
+    /// let individual = tables
+    ///     .add_individual_with_metadata(0, None, None, &metadata)
+    ///     .unwrap();
+    /// let root = tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// for individual in [individual, tskit::IndividualId::NULL] {
+    ///     let sample = tables
+    ///         .add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, individual)
+    ///         .unwrap();
+    ///     tables.add_edge(0., 1., root, sample).unwrap();
+    /// }
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let options = tskit::FastaWriteOptions {
+    ///     reference_base: Some(b'N'),
+    ///     ..Default::default()
+    /// };
+    /// let mut fasta = vec![];
+    /// treeseq
+    ///     .write_fasta_named_by(&mut fasta, options, |m: &IndividualMetadata| m.name.clone())
+    ///     .unwrap();
+    /// assert_eq!(fasta, b">sample_a\nN\n>n2\nN\n");
+    /// # }
+    /// ```
+    pub fn write_fasta_named_by<W, M, F>(
+        &self,
+        writer: &mut W,
+        options: crate::FastaWriteOptions,
+        name: F,
+    ) -> Result<(), TskitError>
+    where
+        W: std::io::Write,
+        M: crate::metadata::IndividualMetadata,
+        F: Fn(&M) -> String,
+    {
+        let individual = self.nodes().individual_slice();
+        let mut names = Vec::with_capacity(self.sample_nodes().len());
+        for &u in self.sample_nodes() {
+            let i = individual[u.as_usize()];
+            let metadata = match i.is_null() {
+                true => None,
+                false => self.individuals().metadata::<M>(i),
+            };
+            names.push(match metadata {
+                Some(Ok(metadata)) => name(&metadata),
+                _ => format!("n{}", u),
+            });
+        }
+        let options = crate::FastaWriteOptions {
+            names: Some(names),
+            ..options
+        };
+        self.write_fasta(writer, options)
+    }
+
+    /// Get the genotypes of all samples at all sites, packed into bits.
+    ///
+    /// See [`PackedGenotypes`](crate::PackedGenotypes).
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`] if any site has more than two alleles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let site = tables.add_site(5., Some(b"A")).unwrap();
+    /// // Node 3 is the parent of nodes 0 and 1
+    /// tables.add_mutation(site, 3, tskit::MutationId::NULL, 1.5, Some(b"G")).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let genotypes = treeseq.packed_biallelic_genotypes().unwrap();
+    /// assert_eq!(genotypes.get(site, 0), Some(true));
+    /// assert_eq!(genotypes.get(site, 1), Some(true));
+    /// assert_eq!(genotypes.get(site, 2), Some(false));
+    /// assert_eq!(genotypes.get(site, 3), None);
+    /// ```
+    pub fn packed_biallelic_genotypes(&self) -> Result<crate::PackedGenotypes, TskitError> {
+        crate::genotypes::packed_biallelic_genotypes(self)
+    }
+
+    /// Get the number of distinct alleles at each site.
+    ///
+    /// The alleles at a site are its ancestral state and the derived
+    /// states of its mutations.
+    /// A site without mutations has one allele.
+    ///
+    /// The result is indexed by site id.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::IndexError`] if a mutation refers to an invalid site.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let site = tables.add_site(5., Some(b"A")).unwrap();
+    /// tables.add_mutation(site, 0, tskit::MutationId::NULL, 0.5, Some(b"G")).unwrap();
+    /// tables.add_mutation(site, 1, tskit::MutationId::NULL, 0.5, Some(b"G")).unwrap();
+    /// tables.add_site(6., Some(b"A")).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// assert_eq!(treeseq.allele_count_per_site().unwrap(), [2, 1]);
+    /// ```
+    pub fn allele_count_per_site(&self) -> Result<Vec<usize>, TskitError> {
+        let sites = self.sites();
+        let mut alleles = sites
+            .iter()
+            .map(|site| vec![site.ancestral_state.unwrap_or_default()])
+            .collect::<Vec<_>>();
+        for mutation in self.mutations().iter() {
+            let site_alleles = match mutation.site.is_null() {
+                true => None,
+                false => alleles.get_mut(mutation.site.as_usize()),
+            }
+            .ok_or(TskitError::IndexError)?;
+            let state = mutation.derived_state.unwrap_or_default();
+            if !site_alleles.contains(&state) {
+                site_alleles.push(state);
+            }
+        }
+        Ok(alleles.iter().map(|a| a.len()).collect())
+    }
+
+    /// Calculate the unfolded site frequency spectrum of `samples`.
+    ///
+    /// Entry `i` of the result is the number of derived alleles
+    /// carried by exactly `i` of the `samples`.
+    /// Each distinct derived state at a site is counted separately,
+    /// so a site with more than one derived allele contributes
+    /// more than one count.
+    /// A derived allele carried by none of the `samples` is counted
+    /// in entry zero.
+    ///
+    /// The result has length `samples.len() + 1`.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`] if any of `samples` is not a sample node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let site = tables.add_site(5., Some(b"A")).unwrap();
+    /// // Node 3 is the parent of nodes 0 and 1
+    /// tables.add_mutation(site, 3, tskit::MutationId::NULL, 1.5, Some(b"G")).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let sfs = treeseq.site_frequency_spectrum(treeseq.sample_nodes()).unwrap();
+    /// assert_eq!(sfs, [0, 0, 1, 0]);
+    /// ```
+    pub fn site_frequency_spectrum(&self, samples: &[NodeId]) -> Result<Vec<u64>, TskitError> {
+        crate::genotypes::site_frequency_spectrum(self, samples)
+    }
+
+    /// Calculate the derived allele frequency at each site
+    /// within each population.
+    ///
+    /// Entry `[i][j]` of the result is the fraction of the samples
+    /// in population `j` that carry a state other than the ancestral
+    /// state at site `i`.
+    /// All derived states at a site are pooled.
+    /// The frequency is NaN for populations without samples.
+    /// Samples not assigned to a population are ignored.
+    ///
+    /// The result has one row per site and one column per
+    /// row of the population table.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::IndexError`] if a sample refers to an invalid population.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// let pop0 = tables.add_population().unwrap();
+    /// let pop1 = tables.add_population().unwrap();
+    /// tables.add_population().unwrap();
+    /// let sample = tskit::NodeFlags::new_sample();
+    /// for population in [pop0, pop0, pop0, pop1] {
+    ///     tables.add_node(sample, 0.0, population, -1).unwrap();
+    /// }
+    /// let parent = tables.add_node(0, 1.0, -1, -1).unwrap();
+    /// let root = tables.add_node(0, 2.0, -1, -1).unwrap();
+    /// for child in [0, 1] {
+    ///     tables.add_edge(0., 10., parent, child).unwrap();
+    /// }
+    /// for child in [parent, 2.into(), 3.into()] {
+    ///     tables.add_edge(0., 10., root, child).unwrap();
+    /// }
+    /// let site = tables.add_site(5., Some(b"A")).unwrap();
+    /// tables.add_mutation(site, parent, tskit::MutationId::NULL, 1.5, Some(b"G")).unwrap();
+    /// tables.full_sort(tskit::TableSortOptions::default()).unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    ///
+    /// let frequencies = treeseq.allele_frequencies_by_population().unwrap();
+    /// assert_eq!(frequencies[0][0], 2. / 3.);
+    /// assert_eq!(frequencies[0][1], 0.);
+    /// // Population 2 has no samples
+    /// assert!(frequencies[0][2].is_nan());
+    /// ```
+    pub fn allele_frequencies_by_population(&self) -> Result<Vec<Vec<f64>>, TskitError> {
+        crate::genotypes::allele_frequencies_by_population(self)
+    }
+
+    /// Calculate the mutational load of each sample.
+    ///
+    /// For each tree, the mutations at the tree's sites that lie on the
+    /// path from a sample to its root, including those on the sample node
+    /// itself, are counted and multiplied by the span of the tree.
+    /// The load of a sample is the sum of these values over all trees.
+    ///
+    /// The result has one entry for each of
+    /// [`TreeSequence::sample_nodes`], in the same order.
+    ///
+    /// # Note
+    ///
+    /// The path to the root is traversed for every sample in every tree,
+    /// so the run time is proportional to the number of samples times
+    /// the number of trees times the depth of the trees.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library is unable
+    /// to allocate a tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    /// let site = tables.add_site(5., Some(b"A")).unwrap();
+    /// // Node 3 is the parent of nodes 0 and 1
+    /// tables.add_mutation(site, 3, tskit::MutationId::NULL, 1.5, Some(b"G")).unwrap();
+    /// let site = tables.add_site(6., Some(b"A")).unwrap();
+    /// tables.add_mutation(site, 0, tskit::MutationId::NULL, 0.5, Some(b"T")).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// assert_eq!(treeseq.mutational_load().unwrap(), [20., 10., 0.]);
+    /// ```
+    pub fn mutational_load(&self) -> Result<Vec<f64>, TskitError> {
+        crate::genotypes::mutational_load(self)
+    }
+
+    /// Get the time units.
+    ///
+    /// See [`TableCollection::time_units`].
+    pub fn time_units(&self) -> Option<String> {
+        let len = unsafe { ll_bindings::tsk_treeseq_get_time_units_length(self.as_ptr()) };
+        if len == 0 {
+            return None;
+        }
+        let units = unsafe { ll_bindings::tsk_treeseq_get_time_units(self.as_ptr()) };
+        let bytes: &[u8] = sys::generate_slice(units, len);
+        std::str::from_utf8(bytes).ok().map(|s| s.to_string())
+    }
+
     /// Get the number of trees.
     pub fn num_trees(&self) -> SizeType {
         self.inner.num_trees().into()
     }
 
+    /// Get the breakpoints between trees.
+    ///
+    /// The slice has length `num_trees + 1`.
+    /// Tree `i` covers the half-open interval
+    /// `[breakpoints[i], breakpoints[i + 1])`.
+    pub fn breakpoints(&self) -> &[Position] {
+        // SAFETY: our pointer is not null and the
+        // tree sequence is initialized.
+        let breakpoints = unsafe { ll_bindings::tsk_treeseq_get_breakpoints(self.as_ptr()) };
+        sys::generate_slice(breakpoints, self.inner.num_trees() + 1)
+    }
+
+    /// Return an [`Iterator`] over the index and interval of each tree.
+    ///
+    /// The trees themselves are visited using [`TreeSequence::tree_iterator`].
+    /// That is a [`StreamingIterator`](streaming_iterator::StreamingIterator),
+    /// which lends out a single [`Tree`] that is updated in place,
+    /// so no [`Iterator`] can yield the trees.
+    /// This iterator can instead be advanced alongside the tree iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streaming_iterator::StreamingIterator;
+    ///
+    /// let mut tables = tskit::TableCollection::new(10.).unwrap();
+    /// tables.add_node(0, 1.0, -1, -1).unwrap();
+    /// tables.add_node(0, 2.0, -1, -1).unwrap();
+    /// for _ in 0..2 {
+    ///     tables.add_node(tskit::NodeFlags::new_sample(), 0.0, -1, -1).unwrap();
+    /// }
+    /// for (left, right, parent) in [(0., 4., 0), (4., 10., 1)] {
+    ///     tables.add_edge(left, right, parent, 2).unwrap();
+    ///     tables.add_edge(left, right, parent, 3).unwrap();
+    /// }
+    /// tables.full_sort(tskit::TableSortOptions::default()).unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    ///
+    /// let intervals = treeseq.tree_intervals().collect::<Vec<_>>();
+    /// assert_eq!(intervals, [(0, (0.0.into(), 4.0.into())), (1, (4.0.into(), 10.0.into()))]);
+    ///
+    /// let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    /// let mut intervals = treeseq.tree_intervals();
+    /// while let Some(tree) = trees.next() {
+    ///     let (_index, interval) = intervals.next().unwrap();
+    ///     assert_eq!(tree.interval(), interval);
+    /// }
+    /// ```
+    pub fn tree_intervals(&self) -> impl Iterator<Item = (usize, (Position, Position))> + '_ {
+        self.breakpoints()
+            .windows(2)
+            .map(|w| (w[0], w[1]))
+            .enumerate()
+    }
+
+    /// Get the number of trees overlapping the half-open
+    /// interval `[left, right)`.
+    ///
+    /// The result is zero if `left >= right`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_edge(0., 500., 0, 1).unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// assert_eq!(treeseq.num_trees(), 2);
+    /// assert_eq!(treeseq.num_trees_in_interval(0., 1000.), 2);
+    /// assert_eq!(treeseq.num_trees_in_interval(0., 500.), 1);
+    /// assert_eq!(treeseq.num_trees_in_interval(499., 501.), 2);
+    /// assert_eq!(treeseq.num_trees_in_interval(500., 1000.), 1);
+    /// assert_eq!(treeseq.num_trees_in_interval(600., 600.), 0);
+    /// assert_eq!(treeseq.num_trees_in_interval(600., 500.), 0);
+    /// ```
+    pub fn num_trees_in_interval<P: Into<Position>>(&self, left: P, right: P) -> SizeType {
+        let left = left.into();
+        let right = right.into();
+        if left >= right {
+            return 0.into();
+        }
+        let breakpoints = self.breakpoints();
+        // Number of trees ending at or before left
+        let before = breakpoints[1..].partition_point(|&b| b <= left);
+        // Number of trees starting before right
+        let until = breakpoints[..breakpoints.len() - 1].partition_point(|&b| b < right);
+        (until.saturating_sub(before) as ll_bindings::tsk_size_t).into()
+    }
+
+    /// Get the number of trees without any edges.
+    ///
+    /// See [`TreeInterface::is_empty`](crate::TreeInterface::is_empty).
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library is unable
+    /// to allocate a tree.
+    pub fn num_empty_trees(&self) -> Result<SizeType, TskitError> {
+        let mut tree_iter = self.tree_iterator(TreeFlags::NO_SAMPLE_COUNTS)?;
+        let mut num_empty: ll_bindings::tsk_size_t = 0;
+        while let Some(tree) = tree_iter.next() {
+            if tree.is_empty() {
+                num_empty += 1;
+            }
+        }
+        Ok(num_empty.into())
+    }
+
+    /// Return `true` if every tree has exactly one root.
+    ///
+    /// # Note
+    ///
+    /// This function iterates over trees, stopping at the first
+    /// tree with more than one root.
+    /// The result is not cached.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library is unable
+    /// to allocate a tree.
+    pub fn is_fully_coalesced(&self) -> Result<bool, TskitError> {
+        let mut tree_iter = self.tree_iterator(TreeFlags::NO_SAMPLE_COUNTS)?;
+        while let Some(tree) = tree_iter.next() {
+            if tree.num_roots() != 1 {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Return the interval and number of roots of each tree
+    /// having more than one root.
+    ///
+    /// Each element is `(left, right, num_roots)`.
+    ///
+    /// # Note
+    ///
+    /// This function iterates over all trees.
+    /// Adjacent trees with multiple roots are reported separately
+    /// rather than merged into a single interval,
+    /// so that each element corresponds to exactly one tree.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError`] if the underlying C library is unable
+    /// to allocate a tree.
+    pub fn multiroot_intervals(&self) -> Result<Vec<(Position, Position, SizeType)>, TskitError> {
+        let mut tree_iter = self.tree_iterator(TreeFlags::NO_SAMPLE_COUNTS)?;
+        let mut intervals = vec![];
+        while let Some(tree) = tree_iter.next() {
+            let num_roots = tree.num_roots();
+            if num_roots > 1 {
+                let (left, right) = tree.interval();
+                intervals.push((left, right, num_roots));
+            }
+        }
+        Ok(intervals)
+    }
+
     /// Calculate the average Kendall-Colijn (`K-C`) distance between
     /// pairs of trees whose intervals overlap.
     ///
+    /// The distance for each overlapping pair of trees is weighted
+    /// by the span of the overlap, and the sum is divided by the
+    /// sequence length.
+    /// See [`TreeSequence::kc_distance_unweighted`] for an average
+    /// that treats each pair equally.
+    ///
     /// # Note
     ///
     /// * [Citation](https://doi.org/10.1093/molbev/msw124)
@@ -293,11 +1234,139 @@ impl TreeSequence {
             .map_err(|e| e.into())
     }
 
+    /// Calculate the unweighted average Kendall-Colijn (`K-C`) distance
+    /// between pairs of trees whose intervals overlap.
+    ///
+    /// Each overlapping pair of trees contributes equally to the
+    /// average, regardless of the span of the overlap.
+    /// See [`TreeSequence::kc_distance`] for the span-weighted average.
+    ///
+    /// # Parameters
+    ///
+    /// * `lambda` specifies the relative weight of topology and branch length.
+    ///    See [`TreeInterface::kc_distance`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::ValueError`] if the sequence lengths differ.
+    /// * [`TskitError`] if the distance between any pair of trees cannot
+    ///   be calculated.
+    ///   See [`TreeInterface::kc_distance`].
+    pub fn kc_distance_unweighted(
+        &self,
+        other: &TreeSequence,
+        lambda: f64,
+    ) -> Result<f64, TskitError> {
+        // SAFETY: both pointers are not null
+        let sequence_length =
+            unsafe { ll_bindings::tsk_treeseq_get_sequence_length(self.as_ptr()) };
+        let other_length = unsafe { ll_bindings::tsk_treeseq_get_sequence_length(other.as_ptr()) };
+        if sequence_length != other_length {
+            return Err(TskitError::ValueError {
+                got: other_length.to_string(),
+                expected: format!("sequence length of {}", sequence_length),
+            });
+        }
+        let mut trees = self.tree_iterator(TreeFlags::SAMPLE_LISTS)?;
+        let mut other_trees = other.tree_iterator(TreeFlags::SAMPLE_LISTS)?;
+        trees.advance();
+        other_trees.advance();
+        let mut total = 0.0;
+        let mut num_pairs = 0;
+        while let (Some(tree), Some(other_tree)) = (trees.get(), other_trees.get()) {
+            total += tree.kc_distance(other_tree, lambda)?;
+            num_pairs += 1;
+            let right = tree.interval().1;
+            let other_right = other_tree.interval().1;
+            if right <= other_right {
+                trees.advance();
+            }
+            if other_right <= right {
+                other_trees.advance();
+            }
+        }
+        Ok(total / num_pairs as f64)
+    }
+
     // FIXME: document
     pub fn num_samples(&self) -> SizeType {
         self.inner.num_samples().into()
     }
 
+    /// Return `true` if node `u` is a sample.
+    ///
+    /// Returns `false` if `u` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// assert!(treeseq.is_sample(0));
+    /// assert!(treeseq.is_sample(1));
+    /// // The root
+    /// assert!(!treeseq.is_sample(2));
+    /// assert!(!treeseq.is_sample(3));
+    /// assert!(!treeseq.is_sample(tskit::NodeId::NULL));
+    /// ```
+    pub fn is_sample<N: Into<NodeId>>(&self, u: N) -> bool {
+        // SAFETY: self pointer is not null
+        unsafe { ll_bindings::tsk_treeseq_is_sample(self.as_ptr(), u.into().into()) }
+    }
+
+    /// Get the number of populations.
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(1.).unwrap();
+    /// # tables.add_population().unwrap();
+    /// # let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::BUILD_INDEXES).unwrap();
+    /// assert_eq!(treeseq.num_populations(), 1);
+    /// ```
+    pub fn num_populations(&self) -> SizeType {
+        self.populations().num_rows()
+    }
+
+    /// Get the number of individuals.
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(1.).unwrap();
+    /// # tables.add_individual(0, None, None).unwrap();
+    /// # let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::BUILD_INDEXES).unwrap();
+    /// assert_eq!(treeseq.num_individuals(), 1);
+    /// ```
+    pub fn num_individuals(&self) -> SizeType {
+        self.individuals().num_rows()
+    }
+
+    /// Get the number of migrations.
+    ///
+    /// ```
+    /// # let tables = tskit::TableCollection::new(1.).unwrap();
+    /// # let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::BUILD_INDEXES).unwrap();
+    /// assert_eq!(treeseq.num_migrations(), 0);
+    /// ```
+    pub fn num_migrations(&self) -> SizeType {
+        self.migrations().num_rows()
+    }
+
+    /// Summarize the contents of the tree sequence.
+    ///
+    /// See [`TreeSequenceSummary`](crate::TreeSequenceSummary).
+    pub fn summary(&self) -> crate::TreeSequenceSummary {
+        crate::TreeSequenceSummary {
+            num_trees: self.num_trees(),
+            // SAFETY: self pointer is not null
+            sequence_length: unsafe { ll_bindings::tsk_treeseq_get_sequence_length(self.as_ptr()) }
+                .into(),
+            num_samples: self.num_samples(),
+            num_nodes: self.nodes().num_rows(),
+            num_edges: self.edges().num_rows(),
+            num_sites: self.sites().num_rows(),
+            num_mutations: self.mutations().num_rows(),
+            num_populations: self.num_populations(),
+        }
+    }
+
     /// Simplify tables and return a new tree sequence.
     ///
     /// # Parameters
@@ -342,6 +1411,126 @@ impl TreeSequence {
         ))
     }
 
+    /// Simplify tables and return a new tree sequence
+    /// along with the mapping of input to output node ids.
+    ///
+    /// This function behaves as [`TreeSequence::simplify`] with
+    /// `idmap` set to `true`.
+    ///
+    /// # Parameters
+    ///
+    /// * `samples`: a slice containing non-null node ids.
+    ///   The tables are simplified with respect to the ancestry
+    ///   of these nodes.
+    /// * `options`: A [`SimplificationOptions`] bit field controlling
+    ///   the behavior of simplification.
+    ///
+    /// # Examples
+    ///
+    /// See [`NodeIdMap`](crate::NodeIdMap).
+    pub fn simplify_with_map<O: Into<SimplificationOptions>>(
+        &self,
+        samples: &[NodeId],
+        options: O,
+    ) -> Result<(Self, super::NodeIdMap), TskitError> {
+        let (treeseq, idmap) = self.simplify(samples, options, true)?;
+        // idmap is always Some when requested.
+        Ok((treeseq, super::NodeIdMap::new(idmap.unwrap_or_default())))
+    }
+
+    /// Return a new tree sequence containing only the sites
+    /// in the half-open interval `[left, right)`.
+    ///
+    /// Mutations at removed sites are also removed.
+    /// All other tables, including the edge table, are unchanged.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::ValueError`] if `right <= left`.
+    /// * [`TskitError`] if the underlying C functions return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(100.).unwrap();
+    /// let root = tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// let leaf = tables.add_node(tskit::NodeFlags::new_sample(), 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_edge(0., 100., root, leaf).unwrap();
+    /// for position in [10., 20., 30.] {
+    ///     let site = tables.add_site(position, None).unwrap();
+    ///     tables.add_mutation(site, leaf, tskit::MutationId::NULL, 0.5, None).unwrap();
+    /// }
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let subset = treeseq.keep_site_interval(15., 30.).unwrap();
+    /// assert_eq!(subset.sites().num_rows(), 1);
+    /// assert_eq!(subset.sites().position(0).unwrap(), 20.);
+    /// assert_eq!(subset.mutations().num_rows(), 1);
+    /// assert_eq!(subset.mutations().site(0).unwrap(), 0);
+    /// assert_eq!(subset.edges().num_rows(), 1);
+    /// ```
+    pub fn keep_site_interval<P: Into<Position>>(
+        &self,
+        left: P,
+        right: P,
+    ) -> Result<Self, TskitError> {
+        let left = left.into();
+        let right = right.into();
+        if right <= left {
+            return Err(TskitError::ValueError {
+                got: format!("[{}, {})", left, right),
+                expected: "left < right".to_string(),
+            });
+        }
+        let mut tables = self.dump_tables()?;
+        let keep_sites = tables
+            .sites()
+            .position_slice()
+            .iter()
+            .map(|&p| ll_bindings::tsk_bool_t::from(p >= left && p < right))
+            .collect::<Vec<_>>();
+        let mut site_map = vec![ll_bindings::TSK_NULL; keep_sites.len()];
+        // SAFETY: table pointer is not null and keep has length num_rows
+        let rv = unsafe {
+            ll_bindings::tsk_site_table_keep_rows(
+                &mut (*tables.as_mut_ptr()).sites,
+                keep_sites.as_ptr(),
+                0,
+                site_map.as_mut_ptr(),
+            )
+        };
+        if rv < 0 {
+            return Err(TskitError::ErrorCode { code: rv });
+        }
+
+        // SAFETY: table pointer is not null
+        let mutations = unsafe { &mut (*tables.as_mut_ptr()).mutations };
+        let mutation_site: &mut [tsk_id_t] =
+            sys::generate_slice_mut(mutations.site, mutations.num_rows);
+        let keep_mutations = mutation_site
+            .iter()
+            .map(|&s| ll_bindings::tsk_bool_t::from(keep_sites[s as usize] != 0))
+            .collect::<Vec<_>>();
+        for s in mutation_site.iter_mut() {
+            *s = site_map[*s as usize];
+        }
+        // Mutation parents are at the same site, so the parents
+        // of retained mutations are always retained.
+        // SAFETY: table pointer is not null and keep has length num_rows
+        let rv = unsafe {
+            ll_bindings::tsk_mutation_table_keep_rows(
+                mutations,
+                keep_mutations.as_ptr(),
+                0,
+                std::ptr::null_mut(),
+            )
+        };
+        if rv < 0 {
+            return Err(TskitError::ErrorCode { code: rv });
+        }
+        tables.tree_sequence(TreeSequenceFlags::default())
+    }
+
     #[cfg(feature = "provenance")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "provenance")))]
     /// Add provenance record with a time stamp.
@@ -398,6 +1587,148 @@ impl TreeSequence {
         handle_tsk_return_value!(rv, crate::ProvenanceId::from(rv))
     }
 
+    /// Get the node time column as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let time = treeseq.nodes_time();
+    /// assert_eq!(time.len(), usize::try_from(treeseq.nodes().num_rows()).unwrap());
+    /// assert_eq!(time[0], 1.0);
+    /// ```
+    pub fn nodes_time(&self) -> &[crate::Time] {
+        self.nodes().time_slice()
+    }
+
+    /// Get the edge parent column as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// # tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_edge(0., 1000., 0, 1).unwrap();
+    /// # tables.add_edge(0., 1000., 0, 2).unwrap();
+    /// # tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let parent = treeseq.edges_parent();
+    /// assert_eq!(parent.len(), usize::try_from(treeseq.edges().num_rows()).unwrap());
+    /// ```
+    pub fn edges_parent(&self) -> &[NodeId] {
+        self.edges().parent_slice()
+    }
+
+    /// Get the edge child column as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// # tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_edge(0., 1000., 0, 1).unwrap();
+    /// # tables.add_edge(0., 1000., 0, 2).unwrap();
+    /// # tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let child = treeseq.edges_child();
+    /// assert_eq!(child.len(), usize::try_from(treeseq.edges().num_rows()).unwrap());
+    /// ```
+    pub fn edges_child(&self) -> &[NodeId] {
+        self.edges().child_slice()
+    }
+
+    /// Get the edge left column as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// # tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_edge(0., 1000., 0, 1).unwrap();
+    /// # tables.add_edge(0., 1000., 0, 2).unwrap();
+    /// # tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let left = treeseq.edges_left();
+    /// assert_eq!(left.len(), usize::try_from(treeseq.edges().num_rows()).unwrap());
+    /// ```
+    pub fn edges_left(&self) -> &[crate::Position] {
+        self.edges().left_slice()
+    }
+
+    /// Get the edge right column as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut tables = tskit::TableCollection::new(1000.).unwrap();
+    /// # tables.add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL).unwrap();
+    /// # tables.add_edge(0., 1000., 0, 1).unwrap();
+    /// # tables.add_edge(0., 1000., 0, 2).unwrap();
+    /// # tables.build_index().unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let right = treeseq.edges_right();
+    /// assert_eq!(right.len(), usize::try_from(treeseq.edges().num_rows()).unwrap());
+    /// ```
+    pub fn edges_right(&self) -> &[crate::Position] {
+        self.edges().right_slice()
+    }
+
+    /// Get the edge insertion order as a slice.
+    ///
+    /// This is the index that the tree sequence uses to add
+    /// edges when moving from left to right along the genome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let order = treeseq.edge_insertion_order();
+    /// assert_eq!(order.len(), usize::try_from(treeseq.edges().num_rows()).unwrap());
+    /// ```
+    pub fn edge_insertion_order(&self) -> &[crate::EdgeId] {
+        // SAFETY: the tree sequence and its tables are not null
+        let indexes = unsafe { &(*(*self.as_ptr()).tables).indexes };
+        match indexes.num_edges {
+            0 => &[],
+            n => sys::generate_slice(indexes.edge_insertion_order, n),
+        }
+    }
+
+    /// Get the edge removal order as a slice.
+    ///
+    /// This is the index that the tree sequence uses to remove
+    /// edges when moving from left to right along the genome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let tables = tskit::TableCollection::from_newick("(n0:1,n1:1);", 10.).unwrap();
+    /// let treeseq = tables.tree_sequence(tskit::TreeSequenceFlags::default()).unwrap();
+    /// let order = treeseq.edge_removal_order();
+    /// assert_eq!(order.len(), usize::try_from(treeseq.edges().num_rows()).unwrap());
+    /// ```
+    pub fn edge_removal_order(&self) -> &[crate::EdgeId] {
+        // SAFETY: the tree sequence and its tables are not null
+        let indexes = unsafe { &(*(*self.as_ptr()).tables).indexes };
+        match indexes.num_edges {
+            0 => &[],
+            n => sys::generate_slice(indexes.edge_removal_order, n),
+        }
+    }
+
     delegate_table_view_api!();
 
     /// Build a lending iterator over edge differences.
@@ -1,3 +1,19 @@
 pub(crate) fn partial_cmp_equal<T: PartialOrd>(lhs: &T, rhs: &T) -> bool {
     matches!(lhs.partial_cmp(rhs), Some(std::cmp::Ordering::Equal))
 }
+
+// Hash the bit pattern of a float.
+// -0.0 and 0.0 compare equal, so they must hash equally, too.
+pub(crate) fn hash_f64<T: Into<f64>, H: std::hash::Hasher>(value: T, state: &mut H) {
+    use std::hash::Hash;
+    let value = value.into();
+    let value = if value == 0.0 { 0.0 } else { value };
+    value.to_bits().hash(state)
+}
+
+// Compare floats so that the comparison is consistent with `hash_f64`.
+// Unlike `==`, a NaN is equal to itself.
+pub(crate) fn hash_eq_f64<T: Into<f64>>(lhs: T, rhs: T) -> bool {
+    let (lhs, rhs) = (lhs.into(), rhs.into());
+    lhs == rhs || lhs.to_bits() == rhs.to_bits()
+}
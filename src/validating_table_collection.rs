@@ -0,0 +1,306 @@
+use crate::sys::bindings as ll_bindings;
+use crate::EdgeId;
+use crate::IndividualFlags;
+use crate::IndividualId;
+use crate::IndividualLocation;
+use crate::IndividualParents;
+use crate::MutationId;
+use crate::NodeFlags;
+use crate::NodeId;
+use crate::PopulationId;
+use crate::Position;
+use crate::SiteId;
+use crate::SizeType;
+use crate::TableCollection;
+use crate::Time;
+use crate::TskitError;
+use ll_bindings::tsk_id_t;
+use ll_bindings::tsk_size_t;
+
+/// A [`TableCollection`] that validates each row as it is added.
+///
+/// [`TableCollection`] allows invalid data to be added, deferring
+/// checks to [`TableCollection::check_integrity`] or to the creation
+/// of a [`crate::TreeSequence`].
+/// In contrast, each `add_*` function of this type returns an error
+/// as soon as an invalid row is encountered, leaving the tables unchanged.
+///
+/// The checks are:
+///
+/// * Node times are finite and populations and individuals refer
+///   to existing rows or are null.
+/// * Edge coordinates satisfy `0 <= left < right <= sequence_length`,
+///   parents and children are existing nodes, and parents are older
+///   than children.
+/// * Site positions satisfy `0 <= position < sequence_length`.
+/// * Mutations refer to existing sites and nodes, parent mutations are
+///   null or existing mutations at the same site, and known times are not
+///   younger than the node.
+/// * Individual parents are null or existing individuals.
+///
+/// Read-only access to the tables is via [`std::ops::Deref`].
+/// Sorting, indexing, and checks that involve more than one row remain
+/// the caller's responsibility.
+///
+/// # Examples
+///
+/// ```
+/// let mut tables = tskit::ValidatingTableCollection::new(100.).unwrap();
+/// let parent = tables.add_node(0, 1.0, -1, -1).unwrap();
+/// let child = tables.add_node(tskit::NodeFlags::new_sample(), 0.0, -1, -1).unwrap();
+/// assert!(tables.add_edge(0., 100., parent, child).is_ok());
+/// // The right coordinate is past the end of the sequence
+/// assert!(tables.add_edge(0., 200., parent, child).is_err());
+/// // The child is older than the parent
+/// assert!(tables.add_edge(0., 100., child, parent).is_err());
+/// assert_eq!(tables.edges().num_rows(), 1);
+/// let tables = tables.into_tables();
+/// ```
+pub struct ValidatingTableCollection {
+    tables: TableCollection,
+}
+
+fn check_id<I: Into<tsk_id_t>>(
+    id: I,
+    num_rows: SizeType,
+    allow_null: bool,
+    column: &str,
+) -> Result<(), TskitError> {
+    let id = id.into();
+    let valid = match id {
+        ll_bindings::TSK_NULL => allow_null,
+        id => id >= 0 && (id as tsk_size_t) < tsk_size_t::from(num_rows),
+    };
+    match valid {
+        true => Ok(()),
+        false => Err(TskitError::ValueError {
+            got: format!("{} {}", column, id),
+            expected: format!("a valid {}", column),
+        }),
+    }
+}
+
+fn check_finite(value: f64, column: &str) -> Result<(), TskitError> {
+    match value.is_finite() {
+        true => Ok(()),
+        false => Err(TskitError::ValueError {
+            got: format!("{} {}", column, value),
+            expected: format!("a finite {}", column),
+        }),
+    }
+}
+
+impl ValidatingTableCollection {
+    /// Create a new, empty, table collection.
+    ///
+    /// # Errors
+    ///
+    /// See [`TableCollection::new`].
+    pub fn new<P: Into<Position>>(sequence_length: P) -> Result<Self, TskitError> {
+        Ok(Self {
+            tables: TableCollection::new(sequence_length)?,
+        })
+    }
+
+    /// Return the tables, ending validation.
+    pub fn into_tables(self) -> TableCollection {
+        self.tables
+    }
+
+    /// Add a row to the node table.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`] if the row is invalid.
+    pub fn add_node<F, T, P, I>(
+        &mut self,
+        flags: F,
+        time: T,
+        population: P,
+        individual: I,
+    ) -> Result<NodeId, TskitError>
+    where
+        F: Into<NodeFlags>,
+        T: Into<Time>,
+        P: Into<PopulationId>,
+        I: Into<IndividualId>,
+    {
+        let time = time.into();
+        let population = population.into();
+        let individual = individual.into();
+        check_finite(time.into(), "node time")?;
+        check_id(
+            population,
+            self.tables.populations().num_rows(),
+            true,
+            "population",
+        )?;
+        check_id(
+            individual,
+            self.tables.individuals().num_rows(),
+            true,
+            "individual",
+        )?;
+        self.tables.add_node(flags, time, population, individual)
+    }
+
+    /// Add a row to the edge table.
+    ///
+    /// # Errors
+    ///
+    /// * [`TskitError::RangeError`] if the coordinates are invalid.
+    /// * [`TskitError::ValueError`] if the nodes are invalid.
+    pub fn add_edge<L, R, P, C>(
+        &mut self,
+        left: L,
+        right: R,
+        parent: P,
+        child: C,
+    ) -> Result<EdgeId, TskitError>
+    where
+        L: Into<Position>,
+        R: Into<Position>,
+        P: Into<NodeId>,
+        C: Into<NodeId>,
+    {
+        let (left, right, parent, child) = (left.into(), right.into(), parent.into(), child.into());
+        self.tables.check_edge(left, right, parent, child)?;
+        let num_nodes = self.tables.nodes().num_rows();
+        check_id(parent, num_nodes, false, "parent node")?;
+        check_id(child, num_nodes, false, "child node")?;
+        let parent_time = self
+            .tables
+            .nodes()
+            .time(parent)
+            .ok_or(TskitError::IndexError)?;
+        let child_time = self
+            .tables
+            .nodes()
+            .time(child)
+            .ok_or(TskitError::IndexError)?;
+        if parent_time <= child_time {
+            return Err(TskitError::ValueError {
+                got: format!("parent time {} and child time {}", parent_time, child_time),
+                expected: "the parent to be older than the child".to_string(),
+            });
+        }
+        self.tables.add_edge(left, right, parent, child)
+    }
+
+    /// Add a row to the site table.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::RangeError`] if the position is invalid.
+    pub fn add_site<P: Into<Position>>(
+        &mut self,
+        position: P,
+        ancestral_state: Option<&[u8]>,
+    ) -> Result<SiteId, TskitError> {
+        let position = f64::from(position.into());
+        let sequence_length = f64::from(self.tables.sequence_length());
+        if !(position >= 0.0 && position < sequence_length) {
+            return Err(TskitError::RangeError(format!(
+                "site position {} is not within [0, {})",
+                position, sequence_length
+            )));
+        }
+        self.tables.add_site(position, ancestral_state)
+    }
+
+    /// Add a row to the mutation table.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`] if the row is invalid.
+    pub fn add_mutation<S, N, P, T>(
+        &mut self,
+        site: S,
+        node: N,
+        parent: P,
+        time: T,
+        derived_state: Option<&[u8]>,
+    ) -> Result<MutationId, TskitError>
+    where
+        S: Into<SiteId>,
+        N: Into<NodeId>,
+        P: Into<MutationId>,
+        T: Into<Time>,
+    {
+        let site = site.into();
+        let node = node.into();
+        let parent = parent.into();
+        let time = time.into();
+        check_id(site, self.tables.sites().num_rows(), false, "site")?;
+        check_id(node, self.tables.nodes().num_rows(), false, "node")?;
+        check_id(
+            parent,
+            self.tables.mutations().num_rows(),
+            true,
+            "parent mutation",
+        )?;
+        if !parent.is_null() && self.tables.mutations().site(parent) != Some(site) {
+            return Err(TskitError::ValueError {
+                got: format!("parent mutation {}", parent),
+                expected: format!("a parent mutation at site {}", site),
+            });
+        }
+        // SAFETY: this function only inspects its argument
+        if !unsafe { ll_bindings::tsk_is_unknown_time(time.into()) } {
+            check_finite(time.into(), "mutation time")?;
+            let node_time = self
+                .tables
+                .nodes()
+                .time(node)
+                .ok_or(TskitError::IndexError)?;
+            if time < node_time {
+                return Err(TskitError::ValueError {
+                    got: format!("mutation time {} and node time {}", time, node_time),
+                    expected: "the mutation to be at least as old as its node".to_string(),
+                });
+            }
+        }
+        self.tables
+            .add_mutation(site, node, parent, time, derived_state)
+    }
+
+    /// Add a row to the population table.
+    ///
+    /// # Errors
+    ///
+    /// See [`TableCollection::add_population`].
+    pub fn add_population(&mut self) -> Result<PopulationId, TskitError> {
+        self.tables.add_population()
+    }
+
+    /// Add a row to the individual table.
+    ///
+    /// # Errors
+    ///
+    /// [`TskitError::ValueError`] if any parent is invalid.
+    pub fn add_individual<F, L, P>(
+        &mut self,
+        flags: F,
+        location: L,
+        parents: P,
+    ) -> Result<IndividualId, TskitError>
+    where
+        F: Into<IndividualFlags>,
+        L: IndividualLocation,
+        P: IndividualParents,
+    {
+        let num_individuals = self.tables.individuals().num_rows();
+        for &p in parents.get_slice() {
+            check_id(p, num_individuals, true, "parent individual")?;
+        }
+        self.tables.add_individual(flags, location, parents)
+    }
+}
+
+impl std::ops::Deref for ValidatingTableCollection {
+    type Target = TableCollection;
+
+    fn deref(&self) -> &Self::Target {
+        &self.tables
+    }
+}
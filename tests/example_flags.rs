@@ -1,4 +1,4 @@
-use tskit::{NodeFlags, RawFlags, SimplificationOptions};
+use tskit::{IndividualFlags, NodeFlags, RawFlags, SimplificationOptions, TreeFlags};
 
 fn clip_invalid_flags() {
     // This value contains bits set to 1
@@ -44,6 +44,46 @@ fn example_node_flags() {
     assert_eq!(node_flags.bits(), f);
 }
 
+fn checked_flags() {
+    // Valid combinations are accepted...
+    let raw =
+        (SimplificationOptions::FILTER_SITES | SimplificationOptions::KEEP_INPUT_ROOTS).bits();
+    let simplification_flags = SimplificationOptions::from_bits_checked(raw).unwrap();
+    assert_eq!(simplification_flags.bits(), raw);
+    let raw = (TreeFlags::SAMPLE_LISTS | TreeFlags::NO_SAMPLE_COUNTS).bits();
+    assert_eq!(TreeFlags::from_bits_checked(raw).unwrap().bits(), raw);
+
+    // ...but unknown bits are errors rather than being unset.
+    let f: RawFlags = 1000000;
+    assert!(SimplificationOptions::from_bits_checked(f).is_err());
+    assert!(TreeFlags::from_bits_checked(1 << 30).is_err());
+
+    // Node flags accept user-defined bits, but not unknown reserved bits.
+    assert_eq!(
+        NodeFlags::from_bits_checked(1 << 16).unwrap().bits(),
+        1 << 16
+    );
+    assert!(NodeFlags::from_bits_checked(1 << 15).is_err());
+}
+
+fn example_individual_flags() {
+    // As for nodes, user-defined bits are above the reserved bits.
+    let alive: RawFlags = 1 << 16;
+    let remembered: RawFlags = 1 << 17;
+    let flags = IndividualFlags::new()
+        .with_user_bits(alive)
+        .with_user_bits(remembered);
+    assert_eq!(flags.user_bits(), alive | remembered);
+
+    // Reserved bits cannot be set this way.
+    assert_eq!(IndividualFlags::new().with_user_bits(1).bits(), 0);
+    assert!(IndividualFlags::from_bits_checked(1).is_err());
+
+    let mut tables = tskit::TableCollection::new(1.).unwrap();
+    let individual = tables.add_individual(flags, None, None).unwrap();
+    assert_eq!(tables.individuals().flags(individual), Some(flags));
+}
+
 #[test]
 fn test_clip_invalid_flags() {
     clip_invalid_flags();
@@ -53,3 +93,13 @@ fn test_clip_invalid_flags() {
 fn test_example_node_flags() {
     example_node_flags();
 }
+
+#[test]
+fn test_checked_flags() {
+    checked_flags();
+}
+
+#[test]
+fn test_example_individual_flags() {
+    example_individual_flags();
+}
@@ -196,3 +196,65 @@ build_metadata_registration_test!(
     GenericMetadata,
     PopulationMetadata
 );
+
+#[cfg(feature = "derive")]
+#[cfg(test)]
+mod test_generic_metadata_type {
+    use tskit::metadata::MetadataRoundtrip;
+
+    #[derive(
+        Debug, PartialEq, serde::Serialize, serde::Deserialize, tskit::metadata::NodeMetadata,
+    )]
+    #[serializer("serde_json")]
+    struct Wrapper<T>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        value: T,
+    }
+
+    #[derive(
+        Debug, PartialEq, serde::Serialize, serde::Deserialize, tskit::metadata::MutationMetadata,
+    )]
+    #[serializer("bincode")]
+    struct Pair<A, B>
+    where
+        A: serde::Serialize + serde::de::DeserializeOwned,
+        B: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        first: A,
+        second: Vec<B>,
+    }
+
+    fn dispatch_node_metadata<T: tskit::metadata::NodeMetadata>(_: &T) {}
+    fn dispatch_mutation_metadata<T: tskit::metadata::MutationMetadata>(_: &T) {}
+
+    #[test]
+    fn test_roundtrip() {
+        let w = Wrapper { value: 3_i32 };
+        dispatch_node_metadata(&w);
+        assert_eq!(Wrapper::<i32>::decode(&w.encode().unwrap()).unwrap(), w);
+
+        let p = Pair {
+            first: String::from("x"),
+            second: vec![1_u8, 2],
+        };
+        dispatch_mutation_metadata(&p);
+        assert_eq!(Pair::<String, u8>::decode(&p.encode().unwrap()).unwrap(), p);
+    }
+
+    #[test]
+    fn test_add_row_with_generic_metadata() {
+        let mut tables = tskit::TableCollection::new(10.).unwrap();
+        let md = Wrapper {
+            value: vec![1.5_f64],
+        };
+        let n = tables.add_node_with_metadata(0, 0.0, -1, -1, &md).unwrap();
+        let decoded = tables
+            .nodes()
+            .metadata::<Wrapper<Vec<f64>>>(n)
+            .unwrap()
+            .unwrap();
+        assert_eq!(decoded, md);
+    }
+}
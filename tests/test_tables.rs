@@ -26,6 +26,906 @@ fn test_empty_table_collection() {
     }
 }
 
+#[test]
+fn test_clear_sites_and_mutations() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    let parent = tables
+        .add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+        .unwrap();
+    let child = tables
+        .add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+        .unwrap();
+    tables.add_edge(0., 10., parent, child).unwrap();
+    let site = tables.add_site(1., None).unwrap();
+    tables
+        .add_mutation(site, child, tskit::MutationId::NULL, 0.5, None)
+        .unwrap();
+
+    tables.clear_mutations().unwrap();
+    assert_eq!(tables.mutations().num_rows(), 0);
+    assert_eq!(tables.sites().num_rows(), 1);
+    assert_eq!(tables.nodes().num_rows(), 2);
+    assert_eq!(tables.edges().num_rows(), 1);
+
+    tables
+        .add_mutation(site, child, tskit::MutationId::NULL, 0.5, None)
+        .unwrap();
+    tables.clear_sites().unwrap();
+    assert_eq!(tables.mutations().num_rows(), 0);
+    assert_eq!(tables.sites().num_rows(), 0);
+    assert_eq!(tables.nodes().num_rows(), 2);
+    assert_eq!(tables.edges().num_rows(), 1);
+}
+
+#[test]
+fn test_load_with_options() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    tables
+        .add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+        .unwrap();
+    let filename = std::env::temp_dir().join("tskit_rust_test_load_with_options.trees");
+    let filename = filename.to_str().unwrap();
+    tables
+        .dump(filename, tskit::TableOutputOptions::default())
+        .unwrap();
+
+    let loaded =
+        tskit::TableCollection::new_from_file_with_options(filename, tskit::TableLoadOptions::NONE)
+            .unwrap();
+    assert_eq!(loaded.nodes().num_rows(), 1);
+
+    let loaded = tskit::TableCollection::new_from_file_with_options(
+        filename,
+        tskit::TableLoadOptions::SKIP_TABLES,
+    )
+    .unwrap();
+    assert_eq!(loaded.sequence_length(), 10.);
+    assert_eq!(loaded.nodes().num_rows(), 0);
+
+    std::fs::remove_file(filename).unwrap();
+}
+
+#[test]
+fn test_time_units_round_trip() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    tables.set_time_units("years").unwrap();
+    assert_eq!(tables.time_units(), Some("years".to_string()));
+    let filename = std::env::temp_dir().join("tskit_rust_test_time_units_round_trip.trees");
+    let filename = filename.to_str().unwrap();
+    tables
+        .dump(filename, tskit::TableOutputOptions::default())
+        .unwrap();
+
+    let loaded = tskit::TableCollection::new_from_file(filename).unwrap();
+    assert_eq!(loaded.time_units(), Some("years".to_string()));
+    std::fs::remove_file(filename).unwrap();
+
+    tables.build_index().unwrap();
+    let treeseq = tables
+        .tree_sequence(tskit::TreeSequenceFlags::default())
+        .unwrap();
+    assert_eq!(treeseq.time_units(), Some("years".to_string()));
+    let tables = treeseq.dump_tables().unwrap();
+    assert_eq!(tables.time_units(), Some("years".to_string()));
+}
+
+#[test]
+fn test_rescale_times() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    let parent = tables
+        .add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+        .unwrap();
+    let child = tables
+        .add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+        .unwrap();
+    let site = tables.add_site(1.0, None).unwrap();
+    tables
+        .add_mutation(site, child, tskit::MutationId::NULL, 0.5, None)
+        .unwrap();
+    tables
+        .add_mutation(
+            site,
+            child,
+            tskit::MutationId::NULL,
+            tskit::Time::from(f64::NAN),
+            None,
+        )
+        .unwrap();
+    tables.rescale_times(2.0).unwrap();
+    assert_eq!(tables.nodes().time(parent).unwrap(), 2.0);
+    assert_eq!(tables.nodes().time(child).unwrap(), 0.0);
+    assert_eq!(tables.mutations().time(0).unwrap(), 1.0);
+    assert!(f64::from(tables.mutations().time(1).unwrap()).is_nan());
+    assert!(tables.rescale_times(-1.0).is_err());
+}
+
+#[test]
+fn test_shift_coordinates() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    tables.add_edge(2., 5., 0, 1).unwrap();
+    tables.add_site(3., None).unwrap();
+    tables.shift_coordinates(100.).unwrap();
+    assert_eq!(tables.sequence_length(), 110.);
+    assert_eq!(tables.edges().left(0).unwrap(), 102.);
+    assert_eq!(tables.edges().right(0).unwrap(), 105.);
+    assert_eq!(tables.sites().position(0).unwrap(), 103.);
+
+    tables.shift_coordinates(-102.).unwrap();
+    assert_eq!(tables.sequence_length(), 8.);
+    assert_eq!(tables.edges().left(0).unwrap(), 0.);
+
+    // Nothing changes upon error
+    assert!(matches!(
+        tables.shift_coordinates(-1.),
+        Err(tskit::TskitError::RangeError(_))
+    ));
+    assert_eq!(tables.sequence_length(), 8.);
+    assert_eq!(tables.sites().position(0).unwrap(), 1.);
+}
+
+#[test]
+fn test_decapitate() {
+    use streaming_iterator::StreamingIterator;
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    //     0
+    //   +-+-+
+    //   1   |
+    // +-+-+ |
+    // 2   3 4
+    let root = tables
+        .add_node(0, 2.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+        .unwrap();
+    let internal = tables
+        .add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+        .unwrap();
+    let mut samples = vec![];
+    for _ in 0..3 {
+        samples.push(
+            tables
+                .add_node(
+                    tskit::NodeFlags::new_sample(),
+                    0.0,
+                    tskit::PopulationId::NULL,
+                    tskit::IndividualId::NULL,
+                )
+                .unwrap(),
+        );
+    }
+    tables.add_edge(0., 10., root, internal).unwrap();
+    tables.add_edge(0., 10., root, samples[2]).unwrap();
+    tables.add_edge(0., 10., internal, samples[0]).unwrap();
+    tables.add_edge(0., 10., internal, samples[1]).unwrap();
+    let site = tables.add_site(1.0, None).unwrap();
+    let old = tables
+        .add_mutation(site, root, tskit::MutationId::NULL, 2.5, None)
+        .unwrap();
+    tables
+        .add_mutation(site, samples[2], old, 0.5, None)
+        .unwrap();
+
+    tables.decapitate(1.5).unwrap();
+    assert_eq!(tables.nodes().num_rows(), 7);
+    assert_eq!(tables.edges().num_rows(), 4);
+    assert_eq!(tables.mutations().num_rows(), 1);
+    assert_eq!(
+        tables.mutations().parent(0).unwrap(),
+        tskit::MutationId::NULL
+    );
+    assert!(!tables.is_indexed());
+
+    tables
+        .full_sort(tskit::TableSortOptions::default())
+        .unwrap();
+    tables.build_index().unwrap();
+    let treeseq = tables
+        .tree_sequence(tskit::TreeSequenceFlags::default())
+        .unwrap();
+    let mut trees = treeseq.tree_iterator(tskit::TreeFlags::default()).unwrap();
+    while let Some(tree) = trees.next() {
+        let mut roots = tree.roots_to_vec();
+        roots.sort();
+        assert_eq!(roots, vec![5, 6]);
+        assert!(roots
+            .iter()
+            .all(|r| treeseq.nodes().time(*r).unwrap() == 1.5));
+        assert_eq!(tree.parent(internal), Some(5.into()));
+        assert_eq!(tree.parent(samples[2]), Some(6.into()));
+    }
+}
+
+#[test]
+fn test_table_collection_diff() {
+    let build = |right: f64| {
+        let mut tables = tskit::TableCollection::new(10.).unwrap();
+        tables
+            .add_node(0, 1.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+            .unwrap();
+        for _ in 0..2 {
+            tables
+                .add_node(0, 0.0, tskit::PopulationId::NULL, tskit::IndividualId::NULL)
+                .unwrap();
+        }
+        tables.add_edge(0., 10., 0, 1).unwrap();
+        tables.add_edge(0., right, 0, 2).unwrap();
+        tables
+    };
+    let tables = build(10.);
+    let same = build(10.);
+    let diff = tables.diff(&same, tskit::TableEqualityOptions::default());
+    assert!(diff.is_empty());
+    assert!(tables.equals(&same, tskit::TableEqualityOptions::default()));
+
+    let other = build(5.);
+    let diff = tables.diff(&other, tskit::TableEqualityOptions::default());
+    assert!(!diff.is_empty());
+    assert!(!tables.equals(&other, tskit::TableEqualityOptions::default()));
+    assert_eq!(diff.edges, Some(tskit::EdgeId::from(1)));
+    assert!(!diff.sequence_length);
+    assert!(diff.nodes.is_none());
+    assert!(diff.sites.is_none());
+    assert!(diff.mutations.is_none());
+    assert!(diff.individuals.is_none());
+    assert!(diff.populations.is_none());
+    assert!(diff.migrations.is_none());
+    assert!(!diff.time_units);
+    assert!(!diff.metadata_schemas);
+
+    // Differences outside of the table rows
+    let mut other = build(10.);
+    other.set_time_units("generations").unwrap();
+    let schemas = tskit::metadata::MetadataSchemaSet {
+        nodes: Some(r#"{"codec":"json"}"#.to_string()),
+        ..Default::default()
+    };
+    other.set_all_json_metadata_schemas(&schemas).unwrap();
+    let diff = tables.diff(&other, tskit::TableEqualityOptions::default());
+    assert!(!diff.is_empty());
+    assert!(!tables.equals(&other, tskit::TableEqualityOptions::default()));
+    assert!(diff.time_units);
+    assert!(diff.metadata_schemas);
+    assert!(!diff.metadata);
+    assert!(!diff.reference_sequence);
+    assert!(diff.nodes.is_none());
+    let diff = tables.diff(&other, tskit::TableEqualityOptions::IGNORE_METADATA);
+    assert!(diff.time_units);
+    assert!(!diff.metadata_schemas);
+
+    #[cfg(feature = "bindings")]
+    {
+        let mut other = build(10.);
+        let metadata = b"abc";
+        let rv = unsafe {
+            tskit::bindings::tsk_table_collection_set_metadata(
+                other.as_mut_ptr(),
+                metadata.as_ptr().cast(),
+                metadata.len() as tskit::bindings::tsk_size_t,
+            )
+        };
+        assert_eq!(rv, 0);
+        let diff = tables.diff(&other, tskit::TableEqualityOptions::default());
+        assert!(diff.metadata);
+        assert!(!tables.equals(&other, tskit::TableEqualityOptions::default()));
+        let diff = tables.diff(&other, tskit::TableEqualityOptions::IGNORE_TS_METADATA);
+        assert!(diff.is_empty());
+    }
+}
+
+#[test]
+fn test_owning_tables_from_rows() {
+    let rows = vec![
+        (tskit::NodeFlags::new_sample(), 0.0, -1, -1),
+        (tskit::NodeFlags::new_sample(), 0.0, -1, -1),
+        (tskit::NodeFlags::default(), 1.0, -1, -1),
+    ];
+    let nodes = tskit::OwningNodeTable::try_from_rows(rows.clone()).unwrap();
+    assert_eq!(nodes.num_rows(), 3);
+    let collected: tskit::OwningNodeTable = rows.into_iter().collect();
+    assert_eq!(collected.num_rows(), 3);
+    assert_eq!(collected.time(2), Some(1.0.into()));
+
+    let rows = [(0., 10., 2, 0), (0., 10., 2, 1)];
+    let edges: tskit::OwningEdgeTable = rows.iter().copied().collect();
+    assert_eq!(edges.num_rows(), 2);
+    assert_eq!(edges.parent(1), Some(2.into()));
+
+    let rows = [(1., Some(b"A".as_slice())), (2., None)];
+    let sites = tskit::OwningSiteTable::try_from_rows(rows).unwrap();
+    assert_eq!(sites.num_rows(), 2);
+    let collected: tskit::OwningSiteTable = rows.into_iter().collect();
+    assert_eq!(collected.position(1), Some(2.0.into()));
+    assert_eq!(collected.ancestral_state(0), Some(b"A".as_slice()));
+
+    let rows = [
+        (0, 1, -1, 1.0, Some(b"T".as_slice())),
+        (1, 2, -1, 0.5, None),
+    ];
+    let mutations = tskit::OwningMutationTable::try_from_rows(rows).unwrap();
+    assert_eq!(mutations.num_rows(), 2);
+    let collected: tskit::OwningMutationTable = rows.into_iter().collect();
+    assert_eq!(collected.site(1), Some(1.into()));
+    assert_eq!(collected.node(1), Some(2.into()));
+
+    let rows = [(0, [0.5], [-1, -1]), (0, [1.5], [0, -1])];
+    let individuals = tskit::OwningIndividualTable::try_from_rows(rows).unwrap();
+    assert_eq!(individuals.num_rows(), 2);
+    let collected: tskit::OwningIndividualTable = rows.into_iter().collect();
+    assert_eq!(
+        collected.parents(1),
+        Some([0.into(), tskit::IndividualId::NULL].as_slice())
+    );
+
+    let rows = [((0., 1.), 1, (0, 1), 10.3), ((1., 2.), 1, (1, 0), 12.0)];
+    let migrations = tskit::OwningMigrationTable::try_from_rows(rows).unwrap();
+    assert_eq!(migrations.num_rows(), 2);
+    let collected: tskit::OwningMigrationTable = rows.into_iter().collect();
+    assert_eq!(collected.source(1), Some(1.into()));
+    assert_eq!(collected.time(1), Some(12.0.into()));
+
+    #[cfg(feature = "derive")]
+    {
+        #[derive(
+            serde::Serialize,
+            serde::Deserialize,
+            Debug,
+            PartialEq,
+            tskit::metadata::PopulationMetadata,
+        )]
+        #[serializer("serde_json")]
+        struct Population {
+            name: String,
+        }
+        let make_rows = || {
+            ["A", "B"].map(|name| Population {
+                name: name.to_string(),
+            })
+        };
+        let populations = tskit::OwningPopulationTable::try_from_rows(make_rows()).unwrap();
+        assert_eq!(populations.num_rows(), 2);
+        let collected: tskit::OwningPopulationTable = make_rows().into_iter().collect();
+        assert_eq!(
+            collected.metadata::<Population>(1.into()).unwrap().unwrap(),
+            Population {
+                name: "B".to_string()
+            }
+        );
+    }
+}
+
+#[test]
+fn test_hash_table_rows() {
+    use std::collections::HashSet;
+    let build = || {
+        let mut tables = tskit::TableCollection::new(10.).unwrap();
+        tables.add_node(0, 1.0, -1, -1).unwrap();
+        tables.add_node(0, 0.0, -1, -1).unwrap();
+        tables.add_edge(0., 10., 0, 1).unwrap();
+        tables
+    };
+    let tables = build();
+    let other = build();
+
+    let edges = tables
+        .edges()
+        .iter()
+        .chain(other.edges().iter())
+        .collect::<HashSet<_>>();
+    assert_eq!(edges.len(), 1);
+
+    let nodes = tables
+        .nodes()
+        .iter()
+        .chain(other.nodes().iter())
+        .collect::<HashSet<_>>();
+    assert_eq!(nodes.len(), 2);
+}
+
+#[test]
+fn test_hash_table_rows_with_nan() {
+    use std::collections::HashSet;
+    let build = || {
+        let mut tables = tskit::TableCollection::new(10.).unwrap();
+        tables.add_node(0, f64::NAN, -1, -1).unwrap();
+        tables.add_site(5., Some(b"A")).unwrap();
+        // A mutation with an unknown time
+        tables.place_mutation(0, 5., b"T", None).unwrap();
+        tables
+    };
+    let tables = build();
+    let other = build();
+
+    let row = tables.mutations().row(0).unwrap();
+    assert_eq!(row, other.mutations().row(0).unwrap());
+    let mutations = tables
+        .mutations()
+        .iter()
+        .chain(other.mutations().iter())
+        .collect::<HashSet<_>>();
+    assert_eq!(mutations.len(), 1);
+    assert!(mutations.contains(&row));
+
+    let row = tables.nodes().row(0).unwrap();
+    assert_eq!(row, other.nodes().row(0).unwrap());
+    let nodes = tables
+        .nodes()
+        .iter()
+        .chain(other.nodes().iter())
+        .collect::<HashSet<_>>();
+    assert_eq!(nodes.len(), 1);
+    assert!(nodes.contains(&row));
+}
+
+#[test]
+fn test_infer_sequence_length() {
+    let mut tables = tskit::TableCollection::new_inferring_length().unwrap();
+    assert!(tables.finalize_length().is_err());
+    tables.add_node(0, 1.0, -1, -1).unwrap();
+    tables.add_node(0, 0.0, -1, -1).unwrap();
+    tables.add_node(0, 0.0, -1, -1).unwrap();
+    tables.add_edge(0., 50., 0, 1).unwrap();
+    tables.add_edge(0., 80., 0, 2).unwrap();
+    tables.add_site(20., None).unwrap();
+    tables.finalize_length().unwrap();
+    assert_eq!(tables.sequence_length(), 80.);
+    assert!(tables
+        .check_integrity(tskit::TableIntegrityCheckFlags::default())
+        .is_ok());
+
+    // A site at the largest coordinate would be out of range
+    tables.add_site(80., None).unwrap();
+    assert!(matches!(
+        tables.finalize_length(),
+        Err(tskit::TskitError::ValueError { .. })
+    ));
+    assert_eq!(tables.sequence_length(), 80.);
+}
+
+#[test]
+fn test_mutation_parent_chain() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    let m0 = tables
+        .add_mutation(0, 0, tskit::MutationId::NULL, 3.0, None)
+        .unwrap();
+    let m1 = tables.add_mutation(0, 0, m0, 2.0, None).unwrap();
+    let m2 = tables.add_mutation(0, 0, m1, 1.0, None).unwrap();
+    let chain = tables.mutations().parent_chain(m2).collect::<Vec<_>>();
+    assert_eq!(chain, [m2, m1, m0]);
+    assert_eq!(tables.mutations().parent_chain(m0).count(), 1);
+    assert_eq!(tables.mutations().parent_chain(3).count(), 0);
+
+    // Malformed data: the parents form a cycle.
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    tables.add_mutation(0, 0, 1, 3.0, None).unwrap();
+    tables.add_mutation(0, 0, 0, 2.0, None).unwrap();
+    assert_eq!(tables.mutations().parent_chain(0).count(), 2);
+
+    // Malformed data: the parent is not in the table.
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    let m0 = tables.add_mutation(0, 0, 5, 3.0, None).unwrap();
+    let m1 = tables.add_mutation(0, 0, m0, 2.0, None).unwrap();
+    let chain = tables.mutations().parent_chain(m1).collect::<Vec<_>>();
+    assert_eq!(chain, [m1, m0]);
+}
+
+#[test]
+fn test_set_indexes() {
+    let mut tables = tskit::TableCollection::from_newick("((n0:1,n1:1):1,n2:2);", 10.).unwrap();
+    let insertion = tables.edge_insertion_order().unwrap().to_vec();
+    let removal = tables.edge_removal_order().unwrap().to_vec();
+    tables.set_indexes(&insertion, &removal).unwrap();
+    assert!(tables.is_indexed());
+    assert_eq!(tables.edge_insertion_order().unwrap(), insertion);
+    assert_eq!(tables.edge_removal_order().unwrap(), removal);
+
+    // Not a permutation
+    let repeated = vec![insertion[0]; insertion.len()];
+    assert!(tables.set_indexes(&repeated, &removal).is_err());
+    let mut out_of_range = insertion.clone();
+    out_of_range[0] = tskit::EdgeId::from(insertion.len() as i32);
+    assert!(tables.set_indexes(&insertion, &out_of_range).is_err());
+    assert!(tables.set_indexes(&insertion[1..], &removal[1..]).is_err());
+    // The existing indexes are untouched by failed calls.
+    assert_eq!(tables.edge_insertion_order().unwrap(), insertion);
+}
+
+#[test]
+fn test_place_mutation() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    tables.add_site(1., Some(b"A")).unwrap();
+    let m0 = tables
+        .place_mutation(0, 5., b"C", Some(0.5.into()))
+        .unwrap();
+    let m1 = tables
+        .place_mutation(1, 5., b"G", Some(0.5.into()))
+        .unwrap();
+    let m2 = tables.place_mutation(1, 1., b"T", None).unwrap();
+    assert_eq!(tables.sites().num_rows(), 2);
+    assert_eq!(tables.mutations().num_rows(), 3);
+    assert_eq!(tables.mutations().site(m0), Some(1.into()));
+    assert_eq!(tables.mutations().site(m1), Some(1.into()));
+    assert_eq!(tables.mutations().site(m2), Some(0.into()));
+    assert_eq!(tables.mutations().node(m1), Some(1.into()));
+    assert_eq!(tables.mutations().derived_state(m1), Some(b"G".as_slice()));
+    assert!(f64::from(tables.mutations().time(m2).unwrap()).is_nan());
+}
+
+#[test]
+fn test_validating_table_collection() {
+    let mut tables = tskit::ValidatingTableCollection::new(10.).unwrap();
+    let parent = tables.add_node(0, 1.0, -1, -1).unwrap();
+    let child = tables.add_node(0, 0.0, -1, -1).unwrap();
+    assert!(tables.add_node(0, f64::NAN, -1, -1).is_err());
+    assert!(tables.add_node(0, 0.0, 0, -1).is_err());
+    let pop = tables.add_population().unwrap();
+    tables.add_node(0, 0.0, pop, -1).unwrap();
+
+    tables.add_edge(0., 10., parent, child).unwrap();
+    assert!(matches!(
+        tables.add_edge(0., 11., parent, child),
+        Err(tskit::TskitError::RangeError(_))
+    ));
+    assert!(tables.add_edge(5., 5., parent, child).is_err());
+    assert!(tables.add_edge(0., 10., parent, 10).is_err());
+    assert!(tables
+        .add_edge(0., 10., tskit::NodeId::NULL, child)
+        .is_err());
+    assert_eq!(tables.edges().num_rows(), 1);
+
+    let site = tables.add_site(5., Some(b"A")).unwrap();
+    assert!(tables.add_site(10., Some(b"A")).is_err());
+    let m = tables
+        .add_mutation(site, child, tskit::MutationId::NULL, 0.5, Some(b"T"))
+        .unwrap();
+    assert!(tables.add_mutation(1, child, -1, 0.5, None).is_err());
+    assert!(tables
+        .add_mutation(site, child, tskit::MutationId::from(5), 0.5, None)
+        .is_err());
+    assert!(tables.add_mutation(site, parent, m, 0.5, None).is_err());
+    tables.add_mutation(site, child, m, 0.25, None).unwrap();
+    assert_eq!(tables.mutations().num_rows(), 2);
+
+    assert!(tables
+        .add_individual(0, None, &[tskit::IndividualId::from(0)])
+        .is_err());
+
+    let tables = tables.into_tables();
+    assert_eq!(tables.nodes().num_rows(), 3);
+}
+
+#[test]
+fn test_sort_mutations() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    let parent = tables.add_node(0, 2.0, -1, -1).unwrap();
+    let c0 = tables.add_node(0, 0.0, -1, -1).unwrap();
+    let c1 = tables.add_node(0, 0.0, -1, -1).unwrap();
+    // Edges are not sorted by parent time, child, left
+    tables.add_edge(5., 10., parent, c1).unwrap();
+    tables.add_edge(0., 10., parent, c0).unwrap();
+    tables.add_edge(0., 5., parent, c1).unwrap();
+    let edges = tables.edges().iter().collect::<Vec<_>>();
+
+    let s0 = tables.add_site(1., Some(b"A")).unwrap();
+    let s1 = tables.add_site(7., Some(b"A")).unwrap();
+    // The child mutation comes before its parent
+    let m0 = tables
+        .add_mutation(s1, c0, tskit::MutationId::from(2), 0.5, Some(b"C"))
+        .unwrap();
+    tables
+        .add_mutation(s0, c1, tskit::MutationId::NULL, 1.0, Some(b"G"))
+        .unwrap();
+    tables
+        .add_mutation(s1, parent, tskit::MutationId::NULL, 3.0, Some(b"T"))
+        .unwrap();
+    assert_eq!(tables.mutations().parent(m0), Some(2.into()));
+
+    tables.sort_mutations().unwrap();
+    assert!(tables.edges().iter().eq(edges.into_iter()));
+    assert!(!tables.is_indexed());
+    assert_eq!(tables.sites().num_rows(), 2);
+
+    let mutations = tables.mutations();
+    assert_eq!(mutations.site(0), Some(s0));
+    assert_eq!(mutations.site(1), Some(s1));
+    assert_eq!(mutations.site(2), Some(s1));
+    assert_eq!(mutations.node(1), Some(parent));
+    assert_eq!(mutations.node(2), Some(c0));
+    assert_eq!(mutations.parent(1), Some(tskit::MutationId::NULL));
+    assert_eq!(mutations.parent(2), Some(1.into()));
+    assert_eq!(mutations.derived_state(2), Some(b"C".as_slice()));
+
+    // The mutation table now satisfies the required ordering
+    assert!(tables
+        .check_integrity(tskit::TableIntegrityCheckFlags::CHECK_MUTATION_ORDERING)
+        .is_ok());
+}
+
+#[test]
+fn test_set_all_json_metadata_schemas() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    assert_eq!(
+        tables.metadata_schemas(),
+        tskit::metadata::MetadataSchemaSet::default()
+    );
+    let node_schema = r#"{"codec":"json","type":"object","properties":{"x":{"type":"number"}}}"#;
+    let population_schema = r#"{"codec":"json","type":"object"}"#;
+    let schemas = tskit::metadata::MetadataSchemaSet {
+        nodes: Some(node_schema.to_string()),
+        populations: Some(population_schema.to_string()),
+        ..Default::default()
+    };
+    tables.set_all_json_metadata_schemas(&schemas).unwrap();
+    let read = tables.metadata_schemas();
+    assert_eq!(read.nodes.as_deref(), Some(node_schema));
+    assert_eq!(read.populations.as_deref(), Some(population_schema));
+    assert!(read.edges.is_none());
+    assert!(read.sites.is_none());
+
+    // A malformed schema changes nothing and names the table
+    let bad = tskit::metadata::MetadataSchemaSet {
+        edges: Some(population_schema.to_string()),
+        migrations: Some(r#"{"codec":"json""#.to_string()),
+        ..Default::default()
+    };
+    let error = tables.set_all_json_metadata_schemas(&bad).unwrap_err();
+    assert!(error.to_string().contains("migration table"));
+    assert_eq!(tables.metadata_schemas(), read);
+
+    // The schemas are preserved by a deep copy
+    let copy = tables.deepcopy().unwrap();
+    assert_eq!(copy.metadata_schemas(), read);
+}
+
+#[test]
+fn test_isolated_nodes() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    assert!(tables.isolated_nodes().is_empty());
+    let root = tables.add_node(0, 2.0, -1, -1).unwrap();
+    let orphan = tables.add_node(0, 1.0, -1, -1).unwrap();
+    let sample = tables
+        .add_node(tskit::NodeFlags::new_sample(), 0.0, -1, -1)
+        .unwrap();
+    let isolated_sample = tables
+        .add_node(tskit::NodeFlags::new_sample(), 0.0, -1, -1)
+        .unwrap();
+    tables.add_edge(0., 10., root, sample).unwrap();
+    assert_eq!(tables.isolated_nodes(), vec![orphan, isolated_sample]);
+    tables.add_edge(0., 5., orphan, isolated_sample).unwrap();
+    assert!(tables.isolated_nodes().is_empty());
+}
+
+#[test]
+fn test_isolated_nodes_with_dangling_edges() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    let parent = tables.add_node(0, 1.0, -1, -1).unwrap();
+    let orphan = tables.add_node(0, 1.0, -1, -1).unwrap();
+    let child = tables
+        .add_node(tskit::NodeFlags::new_sample(), 0.0, -1, -1)
+        .unwrap();
+    // invalid data are allowed by add_edge
+    tables.add_edge(0., 10., parent, 100).unwrap();
+    tables
+        .add_edge(0., 10., tskit::NodeId::NULL, child)
+        .unwrap();
+    tables.add_edge(0., 10., -5, 200).unwrap();
+    assert_eq!(tables.isolated_nodes(), vec![orphan]);
+}
+
+#[test]
+fn test_add_mass_migration() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    let id = tables.add_mass_migration(5.0, 0, 1, (0., 10.), 2).unwrap();
+    assert_eq!(tables.migrations().source(id), Some(0.into()));
+    assert_eq!(tables.migrations().dest(id), Some(1.into()));
+    assert!(matches!(
+        tables.add_mass_migration(5.0, 1, 1, (0., 10.), 2),
+        Err(tskit::TskitError::ValueError { .. })
+    ));
+    assert_eq!(tables.migrations().num_rows(), 1);
+
+    let mut migrations = tskit::OwningMigrationTable::default();
+    assert!(migrations
+        .add_mass_migration(5.0, 0, 0, (0., 10.), 2)
+        .is_err());
+    assert_eq!(migrations.num_rows(), 0);
+}
+
+#[test]
+fn test_csv_round_trip() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    let pop = tables.add_population().unwrap();
+    let parent = tables.add_individual(0, [0.1, 1. / 3.], None).unwrap();
+    let child = tables.add_individual(0, None, [parent]).unwrap();
+    let root = tables.add_node(0, 1. / 7., pop, parent).unwrap();
+    let sample = tables
+        .add_node(tskit::NodeFlags::new_sample(), 0.0, pop, child)
+        .unwrap();
+    tables.add_edge(0., 10. / 3., root, sample).unwrap();
+    let site = tables.add_site(0.1, Some(b"A")).unwrap();
+    tables
+        .add_mutation(site, sample, -1, 0.01, Some(b"G"))
+        .unwrap();
+    tables.add_mutation(site, sample, 0, 0.02, None).unwrap();
+    tables
+        .add_migration((0., 10.), sample, (0, 1), 0.5)
+        .unwrap();
+
+    macro_rules! round_trip {
+        ($table: ident, $owning: ty) => {
+            let mut csv = vec![];
+            tables.$table().write_csv(&mut csv).unwrap();
+            let read = <$owning>::read_csv(csv.as_slice()).unwrap();
+            assert_eq!(read.num_rows(), tables.$table().num_rows());
+            assert!(read.iter().eq(tables.$table().iter()));
+            let mut again = vec![];
+            read.write_csv(&mut again).unwrap();
+            assert_eq!(again, csv);
+        };
+    }
+    round_trip!(edges, tskit::OwningEdgeTable);
+    round_trip!(nodes, tskit::OwningNodeTable);
+    round_trip!(sites, tskit::OwningSiteTable);
+    round_trip!(mutations, tskit::OwningMutationTable);
+    round_trip!(individuals, tskit::OwningIndividualTable);
+    round_trip!(populations, tskit::OwningPopulationTable);
+    round_trip!(migrations, tskit::OwningMigrationTable);
+
+    // Provenance records may contain commas, quotes, and line breaks
+    #[cfg(feature = "provenance")]
+    {
+        tables
+            .add_provenance("{\"command\": \"simulate\",\n \"args\": [1, 2]}")
+            .unwrap();
+        tables.add_provenance("second, record").unwrap();
+        round_trip!(provenances, tskit::provenance::OwningProvenanceTable);
+    }
+
+    // Unknown mutation times are preserved
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    tables.place_mutation(0, 5., b"T", None).unwrap();
+    let mut csv = vec![];
+    tables.mutations().write_csv(&mut csv).unwrap();
+    let read = tskit::OwningMutationTable::read_csv(csv.as_slice()).unwrap();
+    let unknown = f64::from(tables.mutations().time(0).unwrap());
+    assert!(unknown.is_nan());
+    assert_eq!(
+        f64::from(read.time(0).unwrap()).to_bits(),
+        unknown.to_bits()
+    );
+}
+
+#[test]
+fn test_csv_metadata_and_errors() {
+    let csv = "id,metadata\n0,\n1,00ff7b\n";
+    let populations = tskit::OwningPopulationTable::read_csv(csv.as_bytes()).unwrap();
+    assert_eq!(populations.num_rows(), 2);
+    let mut written = vec![];
+    populations.write_csv(&mut written).unwrap();
+    assert_eq!(String::from_utf8(written).unwrap(), csv);
+
+    for bad in [
+        "id,left\n",
+        "id,metadata\n0\n",
+        "id,metadata\n1,\n",
+        "id,metadata\n0,0\n",
+        "id,metadata\n0,zz\n",
+    ] {
+        assert!(matches!(
+            tskit::OwningPopulationTable::read_csv(bad.as_bytes()),
+            Err(tskit::TskitError::ValueError { .. })
+        ));
+    }
+
+    // Provenance text must be UTF-8
+    #[cfg(feature = "provenance")]
+    assert!(matches!(
+        tskit::provenance::OwningProvenanceTable::read_csv(
+            "id,timestamp,record\n0,ff,61\n".as_bytes()
+        ),
+        Err(tskit::TskitError::ValueError { .. })
+    ));
+}
+
+#[test]
+fn test_approx_equals() {
+    use tskit::FloatTolerance;
+
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    tables.add_node(0, 1.0, -1, -1).unwrap();
+    tables.add_node(0, 0.0, -1, -1).unwrap();
+    tables.add_edge(0., 10., 0, 1).unwrap();
+    let mut other = tables.deepcopy().unwrap();
+    other.nodes_mut().time_slice_mut()[0] = (1.0 + 1e-12).into();
+
+    let options = tskit::TableEqualityOptions::default();
+    assert!(!tables.equals(&other, options));
+    for tolerance in [
+        FloatTolerance::Absolute(1e-10),
+        FloatTolerance::Relative(1e-10),
+    ] {
+        assert!(tables.approx_equals(&other, options, tolerance).unwrap());
+        assert!(other.approx_equals(&tables, options, tolerance).unwrap());
+    }
+    assert!(!tables
+        .approx_equals(&other, options, FloatTolerance::Absolute(1e-14))
+        .unwrap());
+    assert!(!tables
+        .approx_equals(&other, options, FloatTolerance::Relative(1e-14))
+        .unwrap());
+    // The arguments are not modified
+    assert!(!tables.equals(&other, options));
+
+    // Differences in other columns are not tolerated
+    other.add_node(0, 0.0, -1, -1).unwrap();
+    assert!(!tables
+        .approx_equals(&other, options, FloatTolerance::Absolute(1.0))
+        .unwrap());
+}
+
+#[test]
+fn test_try_add_edge() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    assert_eq!(tables.try_add_edge(0., 10., 0, 1).unwrap(), 0);
+    assert!(matches!(
+        tables.try_add_edge(0., 11., 0, 1),
+        Err(tskit::TskitError::RangeError(_))
+    ));
+    assert!(matches!(
+        tables.try_add_edge(5., 5., 0, 1),
+        Err(tskit::TskitError::RangeError(_))
+    ));
+    assert!(matches!(
+        tables.try_add_edge(-1., 5., 0, 1),
+        Err(tskit::TskitError::RangeError(_))
+    ));
+    assert!(matches!(
+        tables.try_add_edge(0., 5., 0, tskit::NodeId::NULL),
+        Err(tskit::TskitError::ValueError { .. })
+    ));
+    assert_eq!(tables.edges().num_rows(), 1);
+}
+
+#[test]
+fn test_merge_duplicate_edges() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    tables.add_node(0, 1.0, -1, -1).unwrap();
+    for _ in 0..2 {
+        tables
+            .add_node(tskit::NodeFlags::new_sample(), 0.0, -1, -1)
+            .unwrap();
+    }
+    tables.add_edge(0., 10., 0, 1).unwrap();
+    tables.add_edge(0., 10., 0, 1).unwrap();
+    // Abutting edges are not duplicates
+    tables.add_edge(0., 5., 0, 2).unwrap();
+    tables.add_edge(5., 10., 0, 2).unwrap();
+    tables
+        .full_sort(tskit::TableSortOptions::default())
+        .unwrap();
+    tables.build_index().unwrap();
+
+    assert_eq!(tables.merge_duplicate_edges().unwrap(), 1);
+    assert_eq!(tables.edges().num_rows(), 3);
+    assert!(!tables.is_indexed());
+    assert_eq!(tables.merge_duplicate_edges().unwrap(), 0);
+    tables.build_index().unwrap();
+    assert!(tables
+        .tree_sequence(tskit::TreeSequenceFlags::default())
+        .is_ok());
+}
+
+#[test]
+fn test_node_time_bounds() {
+    let mut tables = tskit::TableCollection::new(10.).unwrap();
+    assert!(tables.nodes().time_bounds().is_none());
+    tables.add_node(0, f64::NAN, -1, -1).unwrap();
+    assert!(tables.nodes().time_bounds().is_none());
+    for time in [3.0, -1.0, f64::INFINITY, 7.5] {
+        tables.add_node(0, time, -1, -1).unwrap();
+    }
+    let (min, max) = tables.nodes().time_bounds().unwrap();
+    assert_eq!(min, -1.0);
+    assert_eq!(max, 7.5);
+}
+
 // We are not checking column getters here.
 // We are not doing integrity checks.
 #[cfg(test)]
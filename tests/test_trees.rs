@@ -357,6 +357,93 @@ fn test_kc_distance_naive_test() {
     assert!((kc - 0.).abs() < f64::EPSILON);
 }
 
+#[test]
+fn test_kc_distance_weighted_vs_unweighted() {
+    // ts1 has two trees, covering [0, 1) and [1, 10).
+    // ts2 has a single tree matching the first tree of ts1.
+    let build = |edges: &[(f64, f64, i32, i32)]| {
+        let mut tables = TableCollection::new(10.).unwrap();
+        for _ in 0..3 {
+            tables
+                .add_node(
+                    NodeFlags::new_sample(),
+                    0.0,
+                    PopulationId::NULL,
+                    IndividualId::NULL,
+                )
+                .unwrap();
+        }
+        tables
+            .add_node(0, 1.0, PopulationId::NULL, IndividualId::NULL)
+            .unwrap();
+        tables
+            .add_node(0, 2.0, PopulationId::NULL, IndividualId::NULL)
+            .unwrap();
+        for &(left, right, parent, child) in edges {
+            tables.add_edge(left, right, parent, child).unwrap();
+        }
+        tables.full_sort(TableSortOptions::default()).unwrap();
+        tables
+            .tree_sequence(TreeSequenceFlags::BUILD_INDEXES)
+            .unwrap()
+    };
+    let ts1 = build(&[
+        (0., 10., 3, 0),
+        (0., 1., 3, 1),
+        (1., 10., 3, 2),
+        (0., 10., 4, 3),
+        (0., 1., 4, 2),
+        (1., 10., 4, 1),
+    ]);
+    let ts2 = build(&[
+        (0., 10., 3, 0),
+        (0., 10., 3, 1),
+        (0., 10., 4, 3),
+        (0., 10., 4, 2),
+    ]);
+
+    // The second tree of ts1 differs from the tree of ts2 by some distance d.
+    // Weighting by span gives 0.9 * d, while the unweighted average is 0.5 * d.
+    let weighted = ts1.kc_distance(&ts2, 0.0).unwrap();
+    let unweighted = ts1.kc_distance_unweighted(&ts2, 0.0).unwrap();
+    assert!(weighted > 0.0);
+    assert!(unweighted > 0.0);
+    assert!((weighted - 1.8 * unweighted).abs() <= 1e-10);
+
+    // Identical tree sequences have zero distance under both modes.
+    assert_eq!(ts1.kc_distance_unweighted(&ts1, 0.0).unwrap(), 0.0);
+}
+
+#[test]
+fn test_deepcopy() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let num_nodes = treeseq.nodes().num_rows();
+    let copy = treeseq.deepcopy().unwrap();
+    let (simplified, _) = copy
+        .simplify(
+            &[2.into(), 3.into()],
+            SimplificationOptions::default(),
+            false,
+        )
+        .unwrap();
+    drop(copy);
+    assert!(simplified.nodes().num_rows() < num_nodes);
+    assert_eq!(treeseq.nodes().num_rows(), num_nodes);
+    assert_eq!(treeseq.num_trees(), 2);
+}
+
+#[test]
+fn test_into_table_collection() {
+    let tables = make_small_table_collection_two_trees();
+    let copy = tables.deepcopy().unwrap();
+    let ptr = tables.as_ptr();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+    let tables = treeseq.into_table_collection().unwrap();
+    // The tables were moved, not copied.
+    assert_eq!(tables.as_ptr(), ptr);
+    assert!(tables.equals(&copy, TableEqualityOptions::default()));
+}
+
 #[test]
 fn test_dump_tables() {
     let tables = make_small_table_collection_two_trees();
@@ -440,3 +527,1208 @@ fn test_simplify_treeseq() {
         assert_ne!(idmap[usize::try_from(i).unwrap()], NodeId::NULL);
     }
 }
+
+#[test]
+fn test_simplify_treeseq_with_map() {
+    let ts = treeseq_from_small_table_collection_two_trees();
+    let samples = ts.sample_nodes();
+    let (_, idmap_raw) = ts
+        .simplify(samples, SimplificationOptions::default(), true)
+        .unwrap();
+    let idmap_raw = idmap_raw.unwrap();
+    let (simplified, idmap) = ts
+        .simplify_with_map(samples, SimplificationOptions::default())
+        .unwrap();
+    assert_eq!(idmap.len(), usize::try_from(ts.nodes().num_rows()).unwrap());
+    assert_eq!(idmap.as_slice(), idmap_raw.as_slice());
+    for &i in samples {
+        assert!(idmap.new_id(i).is_some());
+    }
+    assert_eq!(
+        idmap.old_ids_retained().count(),
+        usize::try_from(simplified.nodes().num_rows()).unwrap()
+    );
+    for old in idmap.old_ids_retained() {
+        assert_eq!(idmap.new_id(old), Some(idmap_raw[old.as_usize()]));
+    }
+}
+
+#[test]
+fn test_level_order_traversal() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    while let Some(tree) = tree_iter.next() {
+        let nodes = tree
+            .traverse_nodes(NodeTraversalOrder::LevelOrder)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            nodes.len(),
+            tree.traverse_nodes(NodeTraversalOrder::Preorder).count()
+        );
+        let position = |u: NodeId| nodes.iter().position(|&n| n == u).unwrap();
+        let depth = |u: NodeId| tree.parents(u).count();
+        for w in nodes.windows(2) {
+            assert!(depth(w[0]) <= depth(w[1]));
+        }
+        for &n in &nodes {
+            let parent = tree.parent(n).unwrap();
+            if !parent.is_null() {
+                assert!(position(parent) < position(n));
+            }
+            let children = tree.children(n).collect::<Vec<_>>();
+            if !children.is_empty() {
+                let first = position(children[0]);
+                for (i, &c) in children.iter().enumerate() {
+                    assert_eq!(position(c), first + i);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_time_ordered_traversals() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let time = treeseq.nodes().time_slice();
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    while let Some(tree) = tree_iter.next() {
+        let num_nodes = tree.traverse_nodes(NodeTraversalOrder::Preorder).count();
+        let ascending = tree
+            .traverse_nodes(NodeTraversalOrder::TimeAscending)
+            .collect::<Vec<_>>();
+        assert_eq!(ascending.len(), num_nodes);
+        for w in ascending.windows(2) {
+            let (t0, t1) = (time[w[0].as_usize()], time[w[1].as_usize()]);
+            assert!(t0 <= t1);
+            if t0 == t1 {
+                assert!(w[0] < w[1]);
+            }
+        }
+        let descending = tree
+            .traverse_nodes(NodeTraversalOrder::TimeDescending)
+            .collect::<Vec<_>>();
+        assert_eq!(descending.len(), num_nodes);
+        for w in descending.windows(2) {
+            let (t0, t1) = (time[w[0].as_usize()], time[w[1].as_usize()]);
+            assert!(t0 >= t1);
+            if t0 == t1 {
+                assert!(w[0] < w[1]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tree_seek_first_and_last() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let mut tree = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+
+    tree.seek_last().unwrap();
+    assert_eq!(tree.get().unwrap().interval().1, 1000.);
+    // Stepping back from the last tree gives the first tree
+    let tree_ref = tree.next_back().unwrap();
+    assert_eq!(tree_ref.interval().0, 0.);
+    assert_eq!(tree_ref.interval().1, 500.);
+    // ...and then we are done
+    assert!(tree.next_back().is_none());
+
+    tree.seek_first().unwrap();
+    assert_eq!(tree.get().unwrap().interval().0, 0.);
+    // Stepping forward from the first tree gives the last tree
+    let tree_ref = tree.next().unwrap();
+    assert_eq!(tree_ref.interval().0, 500.);
+    assert!(tree.next().is_none());
+
+    // Seek after the iterator is exhausted
+    tree.seek_last().unwrap();
+    assert_eq!(tree.get().unwrap().interval().0, 500.);
+    tree.seek_first().unwrap();
+    assert_eq!(tree.get().unwrap().interval().1, 500.);
+    let mut ntrees = 1;
+    while tree.next().is_some() {
+        ntrees += 1;
+    }
+    assert_eq!(ntrees, 2);
+}
+
+#[test]
+fn test_owned_tree_arrays() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let mut parents = vec![];
+    while let Some(tree) = tree_iter.next() {
+        assert_eq!(tree.parent_vec(), tree.parent_array());
+        assert_eq!(tree.left_sib_vec(), tree.left_sib_array());
+        assert_eq!(tree.right_sib_vec(), tree.right_sib_array());
+        assert_eq!(tree.left_child_vec(), tree.left_child_array());
+        assert_eq!(tree.right_child_vec(), tree.right_child_array());
+        parents.push(tree.parent_vec());
+    }
+    assert_eq!(parents.len(), 2);
+    assert_ne!(parents[0], parents[1]);
+}
+
+#[test]
+fn test_empty_trees() {
+    let mut tables = TableCollection::new(1000.).unwrap();
+    tables
+        .add_node(0, 1.0, PopulationId::NULL, IndividualId::NULL)
+        .unwrap();
+    tables
+        .add_node(
+            NodeFlags::new_sample(),
+            0.0,
+            PopulationId::NULL,
+            IndividualId::NULL,
+        )
+        .unwrap();
+    tables
+        .add_node(
+            NodeFlags::new_sample(),
+            0.0,
+            PopulationId::NULL,
+            IndividualId::NULL,
+        )
+        .unwrap();
+    // No edges on [500, 1000)
+    tables.add_edge(0., 500., 0, 1).unwrap();
+    tables.add_edge(0., 500., 0, 2).unwrap();
+    tables.build_index().unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+    assert_eq!(treeseq.num_trees(), 2);
+    assert_eq!(treeseq.num_empty_trees().unwrap(), 1);
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let mut empty = vec![];
+    while let Some(tree) = tree_iter.next() {
+        empty.push(tree.is_empty());
+    }
+    assert_eq!(empty, vec![false, true]);
+
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    assert_eq!(treeseq.num_empty_trees().unwrap(), 0);
+}
+
+#[test]
+fn test_num_trees_in_interval() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    assert_eq!(treeseq.breakpoints(), &[0., 500., 1000.]);
+    assert_eq!(treeseq.num_trees_in_interval(0., 1000.), 2);
+    assert_eq!(treeseq.num_trees_in_interval(0., 500.), 1);
+    assert_eq!(treeseq.num_trees_in_interval(100., 200.), 1);
+    assert_eq!(treeseq.num_trees_in_interval(500., 1000.), 1);
+    assert_eq!(treeseq.num_trees_in_interval(250., 750.), 2);
+    assert_eq!(treeseq.num_trees_in_interval(750., 250.), 0);
+    assert_eq!(treeseq.num_trees_in_interval(500., 500.), 0);
+    assert_eq!(treeseq.num_trees_in_interval(600., 500.), 0);
+    assert_eq!(treeseq.num_trees_in_interval(1000., 2000.), 0);
+}
+
+#[test]
+fn test_root_threshold() {
+    let mut tables = make_small_table_collection();
+    // An isolated sample
+    let isolated = tables
+        .add_node(
+            NodeFlags::new_sample(),
+            0.0,
+            PopulationId::NULL,
+            IndividualId::NULL,
+        )
+        .unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    assert_eq!(tree_iter.root_threshold(), 1);
+    while let Some(tree) = tree_iter.next() {
+        let mut roots = tree.roots_to_vec();
+        roots.sort();
+        assert_eq!(roots, vec![NodeId::from(0), isolated]);
+    }
+
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    tree_iter.set_root_threshold(2).unwrap();
+    assert_eq!(tree_iter.root_threshold(), 2);
+    while let Some(tree) = tree_iter.next() {
+        assert_eq!(tree.roots_to_vec(), vec![NodeId::from(0)]);
+    }
+
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    assert!(tree_iter.set_root_threshold(0).is_err());
+}
+
+#[test]
+fn test_sites_with_mutations() {
+    let mut tables = make_small_table_collection();
+    let s0 = tables.add_site(250., Some(b"A")).unwrap();
+    let s1 = tables.add_site(500., Some(b"A")).unwrap();
+    let parent = tables
+        .add_mutation(s1, 1, MutationId::NULL, 0.5, Some(b"G"))
+        .unwrap();
+    tables
+        .add_mutation(s1, 1, parent, 0.25, Some(b"T"))
+        .unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let sites = treeseq.sites_with_mutations().collect::<Vec<_>>();
+    assert_eq!(sites.len(), 2);
+    assert_eq!(sites[0].0.id, s0);
+    assert!(sites[0].1.is_empty());
+    assert_eq!(sites[1].0.id, s1);
+    assert_eq!(sites[1].1.len(), 2);
+    assert_eq!(sites[1].1[0].id, parent);
+    assert_eq!(sites[1].1[1].parent, parent);
+    assert!(sites[1].1.iter().all(|m| m.site == s1));
+}
+
+#[test]
+fn test_newick_round_trip() {
+    let newick = "((n0:1.5,n1:1.5):1.0,(n2:0.5,n3:0.5):2.0);";
+    let tables = TableCollection::from_newick(newick, 10.).unwrap();
+    assert_eq!(tables.nodes().num_rows(), 7);
+    assert_eq!(tables.edges().num_rows(), 6);
+    assert_eq!(
+        tables
+            .nodes()
+            .flags_slice()
+            .iter()
+            .filter(|f| f.is_sample())
+            .count(),
+        4
+    );
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let tree = tree_iter.next().unwrap();
+    let roots = tree.roots_to_vec();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(tree.time(roots[0]).unwrap(), 2.5);
+    assert_eq!(tree.to_newick(roots[0], 1).unwrap(), newick);
+
+    for malformed in [
+        "",
+        "(n0:1,n1:1)",
+        "((n0:1,n1:1);",
+        "(n0:1,n1:1));",
+        "(n0:1,n1:x);",
+        "(n0,n1);",
+        "(n0:-1,n1:1);",
+        "(n0:1,n1:1); extra",
+    ] {
+        assert!(matches!(
+            TableCollection::from_newick(malformed, 10.),
+            Err(tskit::TskitError::ValueError { .. })
+        ));
+    }
+}
+
+#[test]
+fn test_newick_deep_nesting() {
+    // A caterpillar tree, ((((n0,n1),n2),n3),...)
+    let num_tips = 50_000;
+    let mut newick = "(".repeat(num_tips - 1);
+    newick.push_str("n0:1");
+    for i in 1..num_tips {
+        newick.push_str(&format!(",n{}:1):1", i));
+    }
+    newick.push(';');
+    let tables = TableCollection::from_newick(&newick, 1.).unwrap();
+    assert_eq!(tables.nodes().num_rows(), 2 * num_tips as u64 - 1);
+    assert_eq!(tables.edges().num_rows(), 2 * num_tips as u64 - 2);
+}
+
+#[test]
+fn test_allele_counts() {
+    let mut tables = make_small_table_collection_two_trees();
+    let site = tables.add_site(250., Some(b"0")).unwrap();
+    tables
+        .add_mutation(site, 2, MutationId::NULL, 0.5, Some(b"1"))
+        .unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::SAMPLE_LISTS).unwrap();
+    let tree = tree_iter.next().unwrap();
+    let counts = tree.allele_counts(site).unwrap();
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&b"1".to_vec()], 1);
+    assert_eq!(
+        counts.values().sum::<usize>(),
+        tree.samples_array().unwrap().len()
+    );
+
+    // The site is not in the second tree
+    let tree = tree_iter.next().unwrap();
+    assert!(tree.allele_counts(site).is_err());
+
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let tree = tree_iter.next().unwrap();
+    assert!(matches!(
+        tree.allele_counts(site),
+        Err(tskit::TskitError::NotTrackingSamples)
+    ));
+}
+
+#[test]
+fn test_keep_site_interval() {
+    let mut tables = make_small_table_collection_two_trees();
+    for (position, node) in [(100., 2), (400., 4), (600., 2), (900., 5)] {
+        let site = tables.add_site(position, Some(b"0")).unwrap();
+        let parent = tables
+            .add_mutation(site, node, MutationId::NULL, 0.5, Some(b"1"))
+            .unwrap();
+        tables
+            .add_mutation(site, node, parent, 0.25, Some(b"0"))
+            .unwrap();
+    }
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let subset = treeseq.keep_site_interval(400., 900.).unwrap();
+    assert_eq!(subset.num_trees(), treeseq.num_trees());
+    assert_eq!(subset.edges().num_rows(), treeseq.edges().num_rows());
+    assert_eq!(subset.sites().num_rows(), 2);
+    assert_eq!(subset.sites().position_slice(), &[400., 600.]);
+    assert_eq!(subset.mutations().num_rows(), 4);
+    assert_eq!(subset.mutations().site_slice(), &[0, 0, 1, 1]);
+    assert_eq!(
+        subset.mutations().parent_slice(),
+        &[MutationId::NULL, 0.into(), MutationId::NULL, 2.into()]
+    );
+
+    let subset = treeseq.keep_site_interval(0., 1000.).unwrap();
+    assert!(subset.dump_tables().unwrap().equals(
+        &treeseq.dump_tables().unwrap(),
+        TableEqualityOptions::default()
+    ));
+
+    assert!(treeseq.keep_site_interval(500., 500.).is_err());
+}
+
+#[test]
+fn test_write_fasta() {
+    fn parse_fasta(fasta: &str) -> Vec<(String, String)> {
+        let mut records: Vec<(String, String)> = vec![];
+        for line in fasta.lines() {
+            match line.strip_prefix('>') {
+                Some(name) => records.push((name.to_string(), String::new())),
+                None => records.last_mut().unwrap().1.push_str(line),
+            }
+        }
+        records
+    }
+
+    let mut tables = make_small_table_collection_two_trees();
+    let site = tables.add_site(100., Some(b"A")).unwrap();
+    // Node 1 is above samples 4 and 5 in both trees
+    tables
+        .add_mutation(site, 1, MutationId::NULL, 1.5, Some(b"C"))
+        .unwrap();
+    let site = tables.add_site(600., Some(b"G")).unwrap();
+    // In the second tree, node 1 is also above sample 2
+    tables
+        .add_mutation(site, 1, MutationId::NULL, 1.5, Some(b"T"))
+        .unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let mut fasta = vec![];
+    treeseq
+        .write_fasta(&mut fasta, tskit::FastaWriteOptions::default())
+        .unwrap();
+    let records = parse_fasta(std::str::from_utf8(&fasta).unwrap());
+    assert_eq!(records.len(), 4);
+    let expected = [("n2", "AT"), ("n3", "AG"), ("n4", "CT"), ("n5", "CT")];
+    for ((name, sequence), (expected_name, expected_sequence)) in records.iter().zip(expected) {
+        assert_eq!(name, expected_name);
+        assert_eq!(sequence, expected_sequence);
+    }
+
+    let options = tskit::FastaWriteOptions {
+        line_width: Some(60),
+        reference_base: Some(b'N'),
+        names: Some(vec!["a".into(), "b".into(), "c".into(), "d".into()]),
+    };
+    let mut fasta = vec![];
+    treeseq.write_fasta(&mut fasta, options).unwrap();
+    let fasta = std::str::from_utf8(&fasta).unwrap();
+    assert!(fasta.lines().all(|line| line.len() <= 60));
+    let records = parse_fasta(fasta);
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0].0, "a");
+    for (_, sequence) in &records {
+        assert_eq!(sequence.len(), 1000);
+    }
+    assert_eq!(&records[0].1[100..101], "A");
+    assert_eq!(&records[0].1[600..601], "T");
+    assert_eq!(&records[2].1[100..101], "C");
+    assert_eq!(records[1].1.matches('N').count(), 998);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_sample_sets_by_skips_undecodable_metadata() {
+    #[derive(serde::Serialize, serde::Deserialize, tskit::metadata::IndividualMetadata)]
+    #[serializer("serde_json")]
+    struct Phenotype {
+        phenotype: i32,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize, tskit::metadata::IndividualMetadata)]
+    #[serializer("serde_json")]
+    struct Other {
+        name: String,
+    }
+
+    let mut tables = TableCollection::new(1.).unwrap();
+    let good = tables
+        .add_individual_with_metadata(0, None, None, &Phenotype { phenotype: 1 })
+        .unwrap();
+    let bad = tables
+        .add_individual_with_metadata(
+            0,
+            None,
+            None,
+            &Other {
+                name: "x".to_string(),
+            },
+        )
+        .unwrap();
+    let root = tables
+        .add_node(0, 1.0, PopulationId::NULL, IndividualId::NULL)
+        .unwrap();
+    for individual in [good, bad, good] {
+        let sample = tables
+            .add_node(NodeFlags::new_sample(), 0.0, PopulationId::NULL, individual)
+            .unwrap();
+        tables.add_edge(0., 1., root, sample).unwrap();
+    }
+    tables.build_index().unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let sample_sets = treeseq.sample_sets_by(|m: &Phenotype| m.phenotype).unwrap();
+    assert_eq!(sample_sets.len(), 1);
+    assert_eq!(sample_sets[&1], vec![1, 3]);
+
+    let options = tskit::FastaWriteOptions {
+        reference_base: Some(b'N'),
+        ..Default::default()
+    };
+    let mut fasta = vec![];
+    treeseq
+        .write_fasta_named_by(&mut fasta, options, |m: &Phenotype| {
+            format!("p{}", m.phenotype)
+        })
+        .unwrap();
+    assert_eq!(
+        std::str::from_utf8(&fasta).unwrap(),
+        ">p1\nN\n>n2\nN\n>p1\nN\n"
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_write_fasta_named_by() {
+    #[derive(serde::Serialize, serde::Deserialize, tskit::metadata::IndividualMetadata)]
+    #[serializer("serde_json")]
+    struct IndividualName {
+        name: String,
+    }
+
+    let mut tables = TableCollection::new(4.).unwrap();
+    let mut individuals = vec![];
+    for name in ["a", "b"] {
+        let metadata = IndividualName {
+            name: name.to_string(),
+        };
+        individuals.push(
+            tables
+                .add_individual_with_metadata(0, None, None, &metadata)
+                .unwrap(),
+        );
+    }
+    // An individual without metadata
+    individuals.push(tables.add_individual(0, None, None).unwrap());
+    individuals.push(IndividualId::NULL);
+    let root = tables
+        .add_node(0, 1.0, PopulationId::NULL, IndividualId::NULL)
+        .unwrap();
+    for individual in individuals {
+        let sample = tables
+            .add_node(NodeFlags::new_sample(), 0.0, PopulationId::NULL, individual)
+            .unwrap();
+        tables.add_edge(0., 4., root, sample).unwrap();
+    }
+    let site = tables.add_site(1., Some(b"A")).unwrap();
+    tables
+        .add_mutation(site, 2, MutationId::NULL, 0.5, Some(b"G"))
+        .unwrap();
+    tables.build_index().unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let options = tskit::FastaWriteOptions {
+        reference_base: Some(b'N'),
+        // Replaced by the names from metadata
+        names: Some(vec![]),
+        ..Default::default()
+    };
+    let mut fasta = vec![];
+    treeseq
+        .write_fasta_named_by(&mut fasta, options, |m: &IndividualName| {
+            format!("individual_{}", m.name)
+        })
+        .unwrap();
+    assert_eq!(
+        std::str::from_utf8(&fasta).unwrap(),
+        ">individual_a\nNANN\n>individual_b\nNGNN\n>n3\nNANN\n>n4\nNANN\n"
+    );
+}
+
+#[test]
+fn test_packed_biallelic_genotypes() {
+    let mut tables = make_small_table_collection_two_trees();
+    let site = tables.add_site(100., Some(b"A")).unwrap();
+    tables
+        .add_mutation(site, 1, MutationId::NULL, 1.5, Some(b"C"))
+        .unwrap();
+    let site = tables.add_site(600., Some(b"G")).unwrap();
+    tables
+        .add_mutation(site, 1, MutationId::NULL, 1.5, Some(b"T"))
+        .unwrap();
+    let treeseq = tables
+        .deepcopy()
+        .unwrap()
+        .tree_sequence(TreeSequenceFlags::default())
+        .unwrap();
+
+    let genotypes = treeseq.packed_biallelic_genotypes().unwrap();
+    assert_eq!(genotypes.num_sites(), 2);
+    assert_eq!(genotypes.num_samples(), 4);
+
+    // Compare against the samples below each mutation
+    let samples = treeseq.sample_nodes();
+    let mut trees = treeseq.tree_iterator(TreeFlags::SAMPLE_LISTS).unwrap();
+    while let Some(tree) = trees.next() {
+        let (left, right) = tree.interval();
+        for site in treeseq.sites().iter() {
+            if site.position < left || site.position >= right {
+                continue;
+            }
+            let mut derived = vec![];
+            for mutation in treeseq.mutations().iter() {
+                if mutation.site == site.id {
+                    derived.extend(tree.samples(mutation.node).unwrap());
+                }
+            }
+            for (i, sample) in samples.iter().enumerate() {
+                assert_eq!(
+                    genotypes.get(site.id, i),
+                    Some(derived.contains(sample)),
+                    "{} {}",
+                    site.id,
+                    sample
+                );
+            }
+        }
+    }
+    assert!(genotypes.get(2, 0).is_none());
+    assert!(genotypes.get(0, 4).is_none());
+
+    // A third allele
+    tables
+        .add_mutation(1, 2, MutationId::NULL, 0.5, Some(b"C"))
+        .unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+    assert!(treeseq.packed_biallelic_genotypes().is_err());
+}
+
+#[test]
+fn test_num_sites_and_mutations_per_tree() {
+    let mut tables = make_small_table_collection_two_trees();
+    for (position, num_mutations) in [(0., 1), (499., 2), (500., 0), (999., 3)] {
+        let site = tables.add_site(position, Some(b"0")).unwrap();
+        for _ in 0..num_mutations {
+            tables
+                .add_mutation(site, 3, MutationId::NULL, 0.5, Some(b"1"))
+                .unwrap();
+        }
+    }
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let mut counts = vec![];
+    while let Some(tree) = trees.next() {
+        counts.push((tree.num_sites(), tree.num_mutations()));
+    }
+    // Sites at 500 are in the second tree
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[0].0, 2);
+    assert_eq!(counts[0].1, 3);
+    assert_eq!(counts[1].0, 2);
+    assert_eq!(counts[1].1, 3);
+    let total_sites: u64 = counts.iter().map(|c| u64::from(c.0)).sum();
+    let total_mutations: u64 = counts.iter().map(|c| u64::from(c.1)).sum();
+    assert_eq!(total_sites, u64::from(treeseq.sites().num_rows()));
+    assert_eq!(total_mutations, u64::from(treeseq.mutations().num_rows()));
+}
+
+#[test]
+fn test_topologically_equal_ignores_metadata() {
+    struct Tag(u8);
+    impl tskit::metadata::MetadataRoundtrip for Tag {
+        fn encode(&self) -> Result<Vec<u8>, tskit::metadata::MetadataError> {
+            Ok(vec![self.0])
+        }
+        fn decode(md: &[u8]) -> Result<Self, tskit::metadata::MetadataError> {
+            Ok(Self(md[0]))
+        }
+    }
+    impl tskit::metadata::NodeMetadata for Tag {}
+
+    let build = |root_time: f64, tag: Option<Tag>| {
+        let mut tables = TableCollection::new(10.).unwrap();
+        match tag {
+            Some(tag) => tables
+                .add_node_with_metadata(0, root_time, PopulationId::NULL, IndividualId::NULL, &tag)
+                .unwrap(),
+            None => tables
+                .add_node(0, root_time, PopulationId::NULL, IndividualId::NULL)
+                .unwrap(),
+        };
+        for _ in 0..2 {
+            tables
+                .add_node(
+                    NodeFlags::new_sample(),
+                    0.0,
+                    PopulationId::NULL,
+                    IndividualId::NULL,
+                )
+                .unwrap();
+        }
+        tables.add_edge(0., 10., 0, 1).unwrap();
+        tables.add_edge(0., 10., 0, 2).unwrap();
+        tables.build_index().unwrap();
+        tables.tree_sequence(TreeSequenceFlags::default()).unwrap()
+    };
+
+    let treeseq = build(1.0, None);
+    let with_metadata = build(1.0, Some(Tag(7)));
+    assert!(treeseq.topologically_equal(&with_metadata));
+    assert!(!treeseq.dump_tables().unwrap().equals(
+        &with_metadata.dump_tables().unwrap(),
+        TableEqualityOptions::default()
+    ));
+    let older_root = build(2.0, Some(Tag(7)));
+    assert!(!treeseq.topologically_equal(&older_root));
+}
+
+#[test]
+fn test_samples_by_individual() {
+    let mut tables = TableCollection::new(100.).unwrap();
+    let parent = tables
+        .add_node(0, 1.0, PopulationId::NULL, IndividualId::NULL)
+        .unwrap();
+    for _ in 0..3 {
+        let ind = tables.add_individual(0, None, None).unwrap();
+        for _ in 0..2 {
+            let u = tables
+                .add_node(NodeFlags::new_sample(), 0.0, PopulationId::NULL, ind)
+                .unwrap();
+            tables.add_edge(0., 100., parent, u).unwrap();
+        }
+    }
+    // An individual without sample nodes
+    let ind = tables.add_individual(0, None, None).unwrap();
+    tables.add_node(0, 0.0, PopulationId::NULL, ind).unwrap();
+    tables.full_sort(TableSortOptions::default()).unwrap();
+    tables.build_index().unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let groups = treeseq.samples_by_individual().collect::<Vec<_>>();
+    assert_eq!(groups.len(), 3);
+    for (i, (ind, nodes)) in groups.iter().enumerate() {
+        assert_eq!(*ind, IndividualId::from(i as i32));
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(treeseq.individual_ploidy(*ind), Some(2));
+        for u in nodes {
+            assert_eq!(treeseq.nodes().individual(*u), Some(*ind));
+        }
+    }
+}
+
+#[test]
+fn test_is_fully_coalesced() {
+    let treeseq = treeseq_from_small_table_collection();
+    assert!(treeseq.is_fully_coalesced().unwrap());
+
+    // Nodes 0 and 1 do not coalesce in the first tree
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    assert!(!treeseq.is_fully_coalesced().unwrap());
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let mut num_roots = vec![];
+    while let Some(tree) = trees.next() {
+        num_roots.push(u64::from(tree.num_roots()));
+    }
+    assert_eq!(num_roots, vec![2, 1]);
+}
+
+#[test]
+fn test_tmrca_matrix() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let samples = treeseq.sample_nodes();
+    assert_eq!(samples, &[2, 3, 4, 5]);
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+
+    let tree = trees.next().unwrap();
+    let tmrca = tree.tmrca_matrix(samples).unwrap();
+    let root_time = tree.time(0).unwrap();
+    assert_eq!(tree.mrca(2, 3).unwrap(), 0);
+    assert_eq!(tmrca[0][1], root_time);
+    assert_eq!(tmrca[2][3], 1.0);
+    // Samples 2 and 4 have no common ancestor in the first tree
+    assert!(tree.mrca(2, 4).unwrap().is_null());
+    assert_eq!(tmrca[0][2], f64::INFINITY);
+    for (i, row) in tmrca.iter().enumerate() {
+        assert_eq!(row[i], 0.0);
+        for (j, t) in row.iter().enumerate() {
+            assert_eq!(*t, tmrca[j][i]);
+        }
+    }
+
+    let tree = trees.next().unwrap();
+    let tmrca = tree.tmrca_matrix(samples).unwrap();
+    assert_eq!(tmrca[0][1], root_time);
+    assert_eq!(tmrca[0][2], 1.0);
+    assert_eq!(tmrca[1][3], root_time);
+
+    assert!(tree.tmrca_matrix(&[NodeId::from(100)]).is_err());
+    assert!(tree.mrca(0, 100).is_err());
+}
+
+#[test]
+fn test_for_each_tree_in_windows() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let windows = [0., 250., 500., 501., 1000.].map(Position::from);
+    let mut branch_length = vec![0.0; windows.len() - 1];
+    treeseq
+        .for_each_tree_in_windows(&windows, TreeFlags::default(), |tree, w, span| {
+            branch_length[w] +=
+                f64::from(tree.total_branch_length(false).unwrap()) * f64::from(span)
+        })
+        .unwrap();
+
+    let mut expected = 0.0;
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    while let Some(tree) = trees.next() {
+        expected += f64::from(tree.total_branch_length(true).unwrap());
+    }
+    let total: f64 = branch_length.iter().sum();
+    assert!((total - expected).abs() < 1e-9);
+    // Both trees have a total branch length of 6
+    assert_eq!(branch_length, [1500., 1500., 6., 2994.]);
+
+    for bad in [
+        vec![0.],
+        vec![0., 500.],
+        vec![1., 1000.],
+        vec![0., 500., 500., 1000.],
+    ] {
+        let bad = bad.into_iter().map(Position::from).collect::<Vec<_>>();
+        assert!(treeseq
+            .for_each_tree_in_windows(&bad, TreeFlags::default(), |_, _, _| ())
+            .is_err());
+    }
+}
+
+#[test]
+fn test_site_frequency_spectrum() {
+    let mut tables = make_small_table_collection_two_trees();
+    for (position, mutations) in [
+        (100., vec![(1, 1.5, "T")]),
+        (200., vec![(2, 0.5, "T")]),
+        (600., vec![(1, 1.5, "T"), (3, 1.0, "C")]),
+        (700., vec![(0, 2.5, "T")]),
+        (800., vec![]),
+    ] {
+        let site = tables.add_site(position, Some(b"A")).unwrap();
+        for (node, time, state) in mutations {
+            tables
+                .add_mutation(site, node, MutationId::NULL, time, Some(state.as_bytes()))
+                .unwrap();
+        }
+    }
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let sfs = treeseq
+        .site_frequency_spectrum(treeseq.sample_nodes())
+        .unwrap();
+    assert_eq!(sfs, [0, 2, 1, 1, 1]);
+
+    let sfs = treeseq
+        .site_frequency_spectrum(&[2.into(), 3.into()])
+        .unwrap();
+    assert_eq!(sfs, [1, 3, 1]);
+
+    assert!(treeseq.site_frequency_spectrum(&[0.into()]).is_err());
+}
+
+#[test]
+fn test_tree_balance_indexes() {
+    // A caterpillar tree
+    let tables = TableCollection::from_newick("(((n0:1,n1:1):1,n2:2):1,n3:3);", 100.).unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let tree = trees.next().unwrap();
+    // |3 - 1| + |2 - 1| + |1 - 1|
+    assert_eq!(tree.colless_index().unwrap(), 3);
+    // 3 + 3 + 2 + 1
+    assert_eq!(tree.sackin_index().unwrap(), 9);
+    // 1/1 + 1/2
+    assert!((tree.b1_index().unwrap() - 1.5).abs() < 1e-12);
+    // 2 * (3/8) + 2/4 + 1/2
+    assert!((tree.b2_index(2.0).unwrap() - 1.75).abs() < 1e-12);
+
+    let treeseq = treeseq_from_small_table_collection();
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let tree = trees.next().unwrap();
+    assert_eq!(tree.colless_index().unwrap(), 0);
+    assert_eq!(tree.sackin_index().unwrap(), 2);
+
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    // Two roots
+    let tree = trees.next().unwrap();
+    assert!(tree.colless_index().is_err());
+    assert!(tree.b2_index(2.0).is_err());
+    assert_eq!(tree.sackin_index().unwrap(), 4);
+    // Node 1 has three children
+    let tree = trees.next().unwrap();
+    assert!(tree.colless_index().is_err());
+    assert_eq!(tree.sackin_index().unwrap(), 7);
+}
+
+#[test]
+fn test_allele_count_per_site() {
+    let mut tables = make_small_table_collection_two_trees();
+    // Biallelic, with a recurrent mutation
+    let site = tables.add_site(100., Some(b"A")).unwrap();
+    tables
+        .add_mutation(site, 2, MutationId::NULL, 0.5, Some(b"T"))
+        .unwrap();
+    tables
+        .add_mutation(site, 4, MutationId::NULL, 0.5, Some(b"T"))
+        .unwrap();
+    // Triallelic
+    let site = tables.add_site(700., Some(b"A")).unwrap();
+    let parent = tables
+        .add_mutation(site, 1, MutationId::NULL, 1.5, Some(b"C"))
+        .unwrap();
+    tables
+        .add_mutation(site, 4, parent, 0.5, Some(b"G"))
+        .unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+    assert_eq!(treeseq.allele_count_per_site().unwrap(), [2, 3]);
+}
+
+#[test]
+fn test_treeseq_edge_insertion_and_removal_order() {
+    let tables = make_small_table_collection_two_trees();
+    let insertion = tables.edge_insertion_order().unwrap().to_vec();
+    let removal = tables.edge_removal_order().unwrap().to_vec();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+    let num_edges = usize::try_from(treeseq.edges().num_rows()).unwrap();
+    assert_eq!(treeseq.edge_insertion_order().len(), num_edges);
+    assert_eq!(treeseq.edge_removal_order().len(), num_edges);
+    assert_eq!(treeseq.edge_insertion_order(), insertion);
+    assert_eq!(treeseq.edge_removal_order(), removal);
+
+    let tables = TableCollection::new(1.).unwrap();
+    let treeseq = tables
+        .tree_sequence(TreeSequenceFlags::BUILD_INDEXES)
+        .unwrap();
+    assert!(treeseq.edge_insertion_order().is_empty());
+    assert!(treeseq.edge_removal_order().is_empty());
+}
+
+#[test]
+fn test_num_tracked_samples_multi() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    // First tree: 0 -> {2, 3}, 1 -> {4, 5}
+    let tree = trees.next().unwrap();
+    let nodes = [0, 2, 4].map(NodeId::from);
+    assert_eq!(tree.num_tracked_samples_multi(&nodes).unwrap(), 3);
+    let nodes = [0, 1, 0].map(NodeId::from);
+    assert_eq!(tree.num_tracked_samples_multi(&nodes).unwrap(), 4);
+    // Second tree: 0 -> {1, 3}, 1 -> {2, 4, 5}
+    let tree = trees.next().unwrap();
+    let nodes = [1, 2, 4].map(NodeId::from);
+    assert_eq!(tree.num_tracked_samples_multi(&nodes).unwrap(), 3);
+    let nodes = [2, 0, 1].map(NodeId::from);
+    assert_eq!(tree.num_tracked_samples_multi(&nodes).unwrap(), 4);
+    let nodes = [tree.virtual_root(), 0.into()];
+    assert_eq!(tree.num_tracked_samples_multi(&nodes).unwrap(), 4);
+    assert_eq!(tree.num_tracked_samples_multi(&[]).unwrap(), 0);
+    assert!(tree
+        .num_tracked_samples_multi(&[NodeId::from(100)])
+        .is_err());
+}
+
+#[test]
+fn test_concatenate() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let concatenated = treeseq.concatenate(&[&treeseq]).unwrap();
+    assert_eq!(concatenated.dump_tables().unwrap().sequence_length(), 2000.);
+    assert_eq!(concatenated.num_trees(), 4);
+    // The samples are shared and the two ancestral nodes are copied
+    assert_eq!(concatenated.nodes().num_rows(), 8);
+    assert_eq!(concatenated.sample_nodes(), treeseq.sample_nodes());
+    assert_eq!(
+        concatenated.edges().num_rows().as_usize(),
+        2 * treeseq.edges().num_rows().as_usize()
+    );
+
+    let other = treeseq_from_small_table_collection();
+    assert!(treeseq.concatenate(&[&other]).is_err());
+}
+
+#[test]
+fn test_concatenate_with_populations_and_individuals() {
+    let make_treeseq = |num_populations: usize| {
+        let mut tables = TableCollection::new(10.).unwrap();
+        for _ in 0..num_populations {
+            tables.add_population().unwrap();
+        }
+        let parent = tables.add_individual(0, None, None).unwrap();
+        let child = tables.add_individual(0, None, [parent]).unwrap();
+        let root = tables.add_node(0, 1.0, 0, parent).unwrap();
+        for _ in 0..2 {
+            let sample = tables
+                .add_node(NodeFlags::new_sample(), 0.0, 0, child)
+                .unwrap();
+            tables.add_edge(0., 10., root, sample).unwrap();
+        }
+        tables.full_sort(TableSortOptions::default()).unwrap();
+        tables.build_index().unwrap();
+        tables.tree_sequence(TreeSequenceFlags::default()).unwrap()
+    };
+
+    let treeseq = make_treeseq(1);
+    let concatenated = treeseq.concatenate(&[&treeseq]).unwrap();
+    assert_eq!(concatenated.populations().num_rows(), 1);
+    assert_eq!(concatenated.individuals().num_rows(), 2);
+    assert_eq!(concatenated.nodes().num_rows(), 4);
+    // The copied root keeps its population and individual
+    for u in [0, 3].map(NodeId::from) {
+        assert_eq!(concatenated.nodes().population(u), Some(0.into()));
+        assert_eq!(concatenated.nodes().individual(u), Some(0.into()));
+    }
+
+    let other = make_treeseq(2);
+    match treeseq.concatenate(&[&other]) {
+        Err(tskit::TskitError::ValueError { expected, .. }) => {
+            assert!(expected.contains("population and individual tables"))
+        }
+        _ => panic!("expected a ValueError"),
+    }
+}
+
+#[test]
+fn test_num_nodes_in_tree() {
+    // The first tree is ((2,3)1,4)0 and the second is (2,3,4)0
+    let mut tables = TableCollection::new(10.).unwrap();
+    tables.add_node(0, 2.0, -1, -1).unwrap();
+    tables.add_node(0, 1.0, -1, -1).unwrap();
+    for _ in 0..3 {
+        tables
+            .add_node(NodeFlags::new_sample(), 0.0, -1, -1)
+            .unwrap();
+    }
+    tables.add_edge(0., 5., 1, 2).unwrap();
+    tables.add_edge(0., 5., 1, 3).unwrap();
+    tables.add_edge(0., 5., 0, 1).unwrap();
+    tables.add_edge(0., 10., 0, 4).unwrap();
+    tables.add_edge(5., 10., 0, 2).unwrap();
+    tables.add_edge(5., 10., 0, 3).unwrap();
+    tables.full_sort(TableSortOptions::default()).unwrap();
+    let treeseq = tables
+        .tree_sequence(TreeSequenceFlags::BUILD_INDEXES)
+        .unwrap();
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let mut counts = vec![];
+    while let Some(tree) = trees.next() {
+        counts.push(tree.num_nodes_in_tree());
+    }
+    assert_eq!(counts, [5, 4]);
+
+    // Each tree of this fixture contains every node
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    while let Some(tree) = trees.next() {
+        assert_eq!(tree.num_nodes_in_tree(), 6);
+    }
+}
+
+#[test]
+fn test_child_edges() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let edges = treeseq.edges();
+    let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    while let Some(tree) = trees.next() {
+        let (left, right) = tree.interval();
+        for u in 0..treeseq.nodes().num_rows().as_usize() {
+            let u = NodeId::from(u as i32);
+            let children = tree.children(u).collect::<Vec<_>>();
+            let child_edges = tree.child_edges(u).collect::<Vec<_>>();
+            assert_eq!(child_edges.len(), children.len());
+            for (e, c) in child_edges.into_iter().zip(children) {
+                assert_eq!(edges.parent(e), Some(u));
+                assert_eq!(edges.child(e), Some(c));
+                assert!(edges.left(e).unwrap() <= left);
+                assert!(edges.right(e).unwrap() >= right);
+            }
+        }
+        assert_eq!(tree.child_edges(-1).count(), 0);
+        assert_eq!(tree.child_edges(100).count(), 0);
+    }
+}
+
+#[test]
+fn test_summary() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let summary = treeseq.summary();
+    assert_eq!(summary.num_trees, treeseq.num_trees());
+    assert_eq!(summary.num_samples, 4);
+    assert_eq!(summary.num_nodes, 6);
+    assert_eq!(summary.num_edges, treeseq.edges().num_rows());
+    assert_eq!(summary.num_sites, 0);
+    assert_eq!(summary.sequence_length, 1000.0);
+    let display = summary.to_string();
+    assert!(display.starts_with("TreeSequence\n"));
+    assert!(display.contains("trees:           2\n"));
+    assert!(display.contains("sequence length: 1000\n"));
+}
+
+#[test]
+fn test_tree_intervals() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let intervals = treeseq.tree_intervals().collect::<Vec<_>>();
+    assert_eq!(intervals.len(), treeseq.num_trees().as_usize());
+    let mut tree_iter = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+    let mut index = 0;
+    while let Some(tree) = tree_iter.next() {
+        assert_eq!(intervals[index], (index, tree.interval()));
+        index += 1;
+    }
+    assert_eq!(index, intervals.len());
+}
+
+#[test]
+fn test_multiroot_intervals() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    let intervals = treeseq.multiroot_intervals().unwrap();
+    assert_eq!(intervals.len(), 1);
+    let (left, right, num_roots) = intervals[0];
+    assert_eq!(left, 0.0);
+    assert_eq!(right, 500.0);
+    assert_eq!(num_roots, 2);
+
+    let treeseq = treeseq_from_small_table_collection();
+    assert!(treeseq.multiroot_intervals().unwrap().is_empty());
+}
+
+#[test]
+fn test_allele_frequencies_by_population() {
+    let mut tables = TableCollection::new(100.).unwrap();
+    let pop0 = tables.add_population().unwrap();
+    let pop1 = tables.add_population().unwrap();
+    for population in [pop0, pop0, pop1, pop1] {
+        tables
+            .add_node(NodeFlags::new_sample(), 0.0, population, IndividualId::NULL)
+            .unwrap();
+    }
+    let parent = tables
+        .add_node(0, 1.0, PopulationId::NULL, IndividualId::NULL)
+        .unwrap();
+    let root = tables
+        .add_node(0, 2.0, PopulationId::NULL, IndividualId::NULL)
+        .unwrap();
+    for child in [0, 1, 2] {
+        tables.add_edge(0., 100., parent, child).unwrap();
+    }
+    tables.add_edge(0., 100., root, parent).unwrap();
+    tables.add_edge(0., 100., root, 3).unwrap();
+    let site = tables.add_site(50., Some(b"A")).unwrap();
+    tables
+        .add_mutation(site, parent, MutationId::NULL, 1.5, Some(b"T"))
+        .unwrap();
+    tables.full_sort(TableSortOptions::default()).unwrap();
+    tables.build_index().unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+
+    let frequencies = treeseq.allele_frequencies_by_population().unwrap();
+    assert_eq!(frequencies.len(), 1);
+    assert_eq!(frequencies[0], [1.0, 0.5]);
+}
+
+#[test]
+fn test_mutational_load() {
+    let mut tables = make_small_table_collection_two_trees();
+    // In the first tree, on the branch above sample 2
+    let site = tables.add_site(100., Some(b"A")).unwrap();
+    tables
+        .add_mutation(site, 2, MutationId::NULL, 1.0, Some(b"T"))
+        .unwrap();
+    // In the second tree, on the branch above node 1,
+    // which is ancestral to samples 2, 4, and 5
+    let site = tables.add_site(600., Some(b"A")).unwrap();
+    tables
+        .add_mutation(site, 1, MutationId::NULL, 1.5, Some(b"T"))
+        .unwrap();
+    let treeseq = tables.tree_sequence(TreeSequenceFlags::default()).unwrap();
+    assert_eq!(treeseq.sample_nodes(), [2, 3, 4, 5]);
+    let load = treeseq.mutational_load().unwrap();
+    assert_eq!(load, [1000., 0., 500., 500.]);
+
+    let treeseq = treeseq_from_small_table_collection();
+    assert_eq!(treeseq.mutational_load().unwrap(), [0., 0.]);
+}
+
+#[test]
+fn test_total_branch_length_c() {
+    for treeseq in [
+        treeseq_from_small_table_collection(),
+        treeseq_from_small_table_collection_two_trees(),
+    ] {
+        let mut trees = treeseq.tree_iterator(TreeFlags::default()).unwrap();
+        while let Some(tree) = trees.next() {
+            let expected = tree.total_branch_length(false).unwrap();
+            assert_eq!(tree.total_branch_length_c().unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn test_roots_with_sample_counts() {
+    let treeseq = treeseq_from_small_table_collection_two_trees();
+    for flags in [TreeFlags::default(), TreeFlags::NO_SAMPLE_COUNTS] {
+        let mut tree_iter = treeseq.tree_iterator(flags).unwrap();
+        let mut counts = vec![];
+        while let Some(tree) = tree_iter.next() {
+            let roots = tree
+                .roots_with_sample_counts()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(
+                roots.iter().map(|(r, _)| *r).collect::<Vec<_>>(),
+                tree.roots_to_vec()
+            );
+            counts.push(
+                roots
+                    .into_iter()
+                    .map(|(r, n)| (r, u64::from(n)))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        assert_eq!(counts.len(), 2);
+        // Node 1 is a root in the first tree
+        let mut first = counts[0].clone();
+        first.sort_unstable();
+        assert_eq!(first, [(0.into(), 2), (1.into(), 2)]);
+        assert_eq!(counts[1], [(0.into(), 4)]);
+    }
+}
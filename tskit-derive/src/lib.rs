@@ -1,8 +1,10 @@
 use proc_macro::TokenStream;
 
-fn impl_serde_json_roundtrip(name: &syn::Ident) -> TokenStream {
+fn impl_serde_json_roundtrip(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let gen = quote::quote!(
-        impl ::tskit::metadata::MetadataRoundtrip for #name {
+        impl #impl_generics ::tskit::metadata::MetadataRoundtrip for #name #ty_generics #where_clause {
             fn encode(&self) -> Result<Vec<u8>, ::tskit::metadata::MetadataError> {
                 match ::serde_json::to_string(self) {
                     Ok(x) => Ok(x.as_bytes().to_vec()),
@@ -26,9 +28,11 @@ fn impl_serde_json_roundtrip(name: &syn::Ident) -> TokenStream {
     gen.into()
 }
 
-fn impl_serde_bincode_roundtrip(name: &syn::Ident) -> TokenStream {
+fn impl_serde_bincode_roundtrip(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let gen = quote::quote!(
-        impl ::tskit::metadata::MetadataRoundtrip for #name {
+        impl #impl_generics ::tskit::metadata::MetadataRoundtrip for #name #ty_generics #where_clause {
             fn encode(&self) -> Result<Vec<u8>, ::tskit::metadata::MetadataError> {
                 match ::bincode::serialize(&self) {
                     Ok(x) => Ok(x),
@@ -50,8 +54,55 @@ fn impl_serde_bincode_roundtrip(name: &syn::Ident) -> TokenStream {
     gen.into()
 }
 
-fn impl_metadata_roundtrip_macro(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
+fn impl_serde_bincode2_roundtrip(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let gen = quote::quote!(
+        impl #impl_generics ::tskit::metadata::MetadataRoundtrip for #name #ty_generics #where_clause {
+            fn encode(&self) -> Result<Vec<u8>, ::tskit::metadata::MetadataError> {
+                match ::bincode::serde::encode_to_vec(self, ::bincode::config::standard()) {
+                    Ok(x) => Ok(x),
+                    Err(e) => {
+                        Err(::tskit::metadata::MetadataError::RoundtripError { value: Box::new(e) })
+                    }
+                }
+            }
+            fn decode(md: &[u8]) -> Result<Self, ::tskit::metadata::MetadataError> {
+                match ::bincode::serde::decode_from_slice(md, ::bincode::config::standard()) {
+                    Ok((x, _)) => Ok(x),
+                    Err(e) => {
+                        Err(::tskit::metadata::MetadataError::RoundtripError { value: Box::new(e) })
+                    }
+                }
+            }
+        }
+    );
+    gen.into()
+}
+
+// Require the serde traits with the span of the type name,
+// so that a missing derive is reported at the type rather
+// than inside of the generated functions.
+fn impl_serde_bounds_check(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let gen = quote::quote_spanned!(name.span() =>
+        const _: () = {
+            fn requires_serialize_and_deserialize<T>()
+            where
+                T: ::serde::Serialize + ::serde::de::DeserializeOwned,
+            {
+            }
+            #[allow(dead_code)]
+            fn metadata_requires_serialize_and_deserialize #impl_generics () #where_clause {
+                requires_serialize_and_deserialize::<#name #ty_generics>();
+            }
+        };
+    );
+    gen.into()
+}
+
+fn impl_metadata_roundtrip_macro(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
     let attrs = &ast.attrs;
 
     for attr in attrs.iter() {
@@ -59,13 +110,17 @@ fn impl_metadata_roundtrip_macro(ast: &syn::DeriveInput) -> Result<TokenStream,
             let lit: syn::LitStr = attr.parse_args().unwrap();
             let serializer = lit.value();
 
-            if &serializer == "serde_json" {
-                return Ok(impl_serde_json_roundtrip(name));
+            let mut roundtrip = if &serializer == "serde_json" {
+                impl_serde_json_roundtrip(ast)
             } else if &serializer == "bincode" {
-                return Ok(impl_serde_bincode_roundtrip(name));
+                impl_serde_bincode_roundtrip(ast)
+            } else if &serializer == "bincode2" {
+                impl_serde_bincode2_roundtrip(ast)
             } else {
                 proc_macro_error::abort!(serializer, "is not a supported protocol.");
-            }
+            };
+            roundtrip.extend(impl_serde_bounds_check(ast));
+            return Ok(roundtrip);
         } else {
             proc_macro_error::abort!(attr.path, "is not a supported attribute.");
         }
@@ -79,6 +134,14 @@ macro_rules! make_derive_metadata_tag {
         #[proc_macro_error::proc_macro_error]
         #[proc_macro_derive($metadatatag, attributes(serializer))]
         /// Register a type as metadata.
+        ///
+        /// The type must implement `serde::Serialize` and
+        /// `serde::de::DeserializeOwned`, which usually means
+        /// deriving `serde::Serialize` and `serde::Deserialize`.
+        ///
+        /// Generic types are supported. The generated impls copy the
+        /// type's generic parameters and `where` clause, so any bounds
+        /// needed for serialization must be declared on the type.
         pub fn $function(input: TokenStream) -> TokenStream {
             let ast: syn::DeriveInput = match syn::parse(input) {
                 Ok(ast) => ast,
@@ -86,8 +149,9 @@ macro_rules! make_derive_metadata_tag {
             };
             let mut roundtrip = impl_metadata_roundtrip_macro(&ast).unwrap();
             let name = &ast.ident;
+            let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
             let gen: proc_macro::TokenStream = quote::quote!(
-                impl ::tskit::metadata::$metadatatag for #name {}
+                impl #impl_generics ::tskit::metadata::$metadatatag for #name #ty_generics #where_clause {}
             )
             .into();
             roundtrip.extend(gen);